@@ -0,0 +1,183 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+// Fast stages are repeated until the time budget is used up, but not
+// endlessly
+const MAX_RUNS: usize = 1000;
+
+/// How long a single stage took over all of its runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timing {
+    pub runs: usize,
+    pub median: Duration,
+    pub min: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayTimings {
+    pub parse: Timing,
+    pub part1: Timing,
+    pub part2: Option<Timing>,
+}
+
+impl DayTimings {
+    pub fn total(&self) -> Duration {
+        self.parse.median + self.part1.median + self.part2.map_or(Duration::ZERO, |t| t.median)
+    }
+}
+
+/// Run `f` at least once and then repeatedly until `budget` has passed,
+/// returning the timing together with the result of the last run
+pub fn measure<T>(budget: Duration, mut f: impl FnMut() -> T) -> (Timing, T) {
+    let mut samples = Vec::new();
+    let started = Instant::now();
+
+    let result = loop {
+        let start = Instant::now();
+        let result = black_box(f());
+        samples.push(start.elapsed());
+
+        if started.elapsed() >= budget || samples.len() >= MAX_RUNS {
+            break result;
+        }
+    };
+
+    samples.sort();
+    let timing = Timing {
+        runs: samples.len(),
+        median: samples[samples.len() / 2],
+        min: samples[0],
+    };
+
+    (timing, result)
+}
+
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn cell(timing: Option<Timing>) -> String {
+    timing.map_or(String::from("-"), |timing| {
+        format!("{:.3} ms", milliseconds(timing.median))
+    })
+}
+
+/// A table with the median time of every stage per day
+pub fn table(results: &[(usize, DayTimings)]) -> String {
+    let mut rows = vec![[
+        String::from("Day"),
+        String::from("Parse"),
+        String::from("Part 1"),
+        String::from("Part 2"),
+        String::from("Total"),
+    ]];
+    for (day, timings) in results {
+        rows.push([
+            day.to_string(),
+            cell(Some(timings.parse)),
+            cell(Some(timings.part1)),
+            cell(timings.part2),
+            format!("{:.3} ms", milliseconds(timings.total())),
+        ]);
+    }
+    let total = results.iter().map(|(_, timings)| timings.total()).sum();
+    rows.push([
+        String::from("All"),
+        String::new(),
+        String::new(),
+        String::new(),
+        format!("{:.3} ms", milliseconds(total)),
+    ]);
+
+    let widths: [usize; 5] =
+        std::array::from_fn(|column| rows.iter().map(|row| row[column].len()).max().unwrap());
+
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(widths)
+                .map(|(text, width)| format!("{text:>width$}"))
+                .collect::<Vec<_>>()
+                .join(" | ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn timing_json(timing: Option<Timing>) -> String {
+    match timing {
+        Some(timing) => format!(
+            "{{\"runs\": {}, \"median_ms\": {:.6}, \"min_ms\": {:.6}}}",
+            timing.runs,
+            milliseconds(timing.median),
+            milliseconds(timing.min)
+        ),
+        None => String::from("null"),
+    }
+}
+
+/// A JSON report of all timings, `timestamp` is in seconds since the Unix
+/// epoch so reports from different runs can be compared
+pub fn json(results: &[(usize, DayTimings)], timestamp: u64) -> String {
+    let days = results
+        .iter()
+        .map(|(day, timings)| {
+            format!(
+                "    {{\"day\": {day}, \"parse\": {}, \"part1\": {}, \"part2\": {}}}",
+                timing_json(Some(timings.parse)),
+                timing_json(Some(timings.part1)),
+                timing_json(timings.part2)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+
+    format!("{{\n  \"timestamp\": {timestamp},\n  \"days\": [\n{days}\n  ]\n}}\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_runs_at_least_once() {
+        let mut calls = 0;
+        let (timing, result) = measure(Duration::ZERO, || {
+            calls += 1;
+            calls
+        });
+
+        assert_eq!(timing.runs, 1);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn report_formats() {
+        let timing = Timing {
+            runs: 3,
+            median: Duration::from_micros(1500),
+            min: Duration::from_millis(1),
+        };
+        let results = [(
+            4,
+            DayTimings {
+                parse: timing,
+                part1: timing,
+                part2: None,
+            },
+        )];
+
+        assert_eq!(
+            table(&results),
+            "Day |    Parse |   Part 1 | Part 2 |    Total
+  4 | 1.500 ms | 1.500 ms |      - | 3.000 ms
+All |          |          |        | 3.000 ms"
+        );
+        assert!(json(&results, 0).contains(
+            "{\"day\": 4, \"parse\": {\"runs\": 3, \"median_ms\": 1.500000, \"min_ms\": 1.000000}"
+        ));
+        assert!(json(&results, 0).contains("\"part2\": null}"));
+    }
+}
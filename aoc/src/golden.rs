@@ -0,0 +1,92 @@
+use std::fs;
+
+use common::Answer;
+
+use crate::{day_dir, Part};
+
+/// The known correct answers for a day's real input, read from the
+/// `answers.toml` file next to it
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GoldenAnswers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl GoldenAnswers {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+// Only the subset of TOML the answer files need: comments and `part1` or
+// `part2` keys with an integer or a basic string value
+pub fn parse(text: &str) -> Result<GoldenAnswers, String> {
+    let mut answers = GoldenAnswers::default();
+
+    for (number, line) in text.lines().enumerate().map(|(i, line)| (i + 1, line)) {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {number}: expected 'key = value'"))?;
+        let value = value.trim();
+        let answer = if let Some(text) = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+        {
+            Answer::Text(String::from(text))
+        } else {
+            Answer::Number(
+                value
+                    .replace('_', "")
+                    .parse()
+                    .map_err(|_| format!("line {number}: expected a number or a string"))?,
+            )
+        };
+
+        let slot = match key.trim() {
+            "part1" => &mut answers.part1,
+            "part2" => &mut answers.part2,
+            key => return Err(format!("line {number}: unknown key '{key}'")),
+        };
+        if slot.replace(answer).is_some() {
+            return Err(format!("line {number}: duplicate key '{}'", key.trim()));
+        }
+    }
+
+    Ok(answers)
+}
+
+pub fn load(day: usize) -> Result<GoldenAnswers, String> {
+    let path = day_dir(day).join("answers.toml");
+    let text = fs::read_to_string(&path)
+        .map_err(|err| format!("Could not read {}: {err}", path.display()))?;
+
+    parse(&text).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers = parse("# Day 17\npart1 = \"7,3,5\"\npart2 = 105_734\n").unwrap();
+
+        assert_eq!(answers.get(Part::One), Some(&Answer::from("7,3,5")));
+        assert_eq!(answers.get(Part::Two), Some(&Answer::Number(105734)));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse("part3 = 1").is_err());
+        assert!(parse("part1 = one").is_err());
+        assert!(parse("part1 = 1\npart1 = 2").is_err());
+    }
+}
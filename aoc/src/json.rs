@@ -0,0 +1,47 @@
+use std::fmt::Write;
+
+use common::Answer;
+
+/// A JSON string literal for `text`
+pub fn string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Numeric answers become JSON numbers and text answers JSON strings
+pub fn answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(text) => string(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_strings() {
+        assert_eq!(string("6,1"), "\"6,1\"");
+        assert_eq!(string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn answers() {
+        assert_eq!(answer(&Answer::Number(42)), "42");
+        assert_eq!(answer(&Answer::from("co,de")), "\"co,de\"");
+    }
+}
@@ -0,0 +1,364 @@
+use std::{
+    error::Error,
+    fmt,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use common::{Answer, InputError};
+use grid::Image;
+
+use bench::DayTimings;
+
+pub mod bench;
+pub mod golden;
+pub mod json;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The answer to one part together with how long solving it took
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartAnswer {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Why a day has no answers for an input
+#[derive(Debug)]
+pub enum SolveError {
+    /// The input doesn't parse
+    Input(Box<dyn InputError>),
+    /// The input parses, but `part` has no answer for it
+    Part { part: Part, error: Box<dyn Error> },
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Input(err) => write!(f, "invalid input at {}: {err}", err.location()),
+            SolveError::Part { part, error } => write!(f, "part {part} has no answer: {error}"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// What a day's part returns: just an answer, or a `Result` for the parts
+/// that can find an input they can't solve even though it parses
+pub trait PartOutput {
+    fn into_result(self) -> Result<Answer, Box<dyn Error>>;
+}
+
+impl PartOutput for Answer {
+    fn into_result(self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self)
+    }
+}
+
+impl<E: Error + 'static> PartOutput for Result<Answer, E> {
+    fn into_result(self) -> Result<Answer, Box<dyn Error>> {
+        self.map_err(|err| Box::new(err) as Box<dyn Error>)
+    }
+}
+
+pub type Answers = Vec<PartAnswer>;
+pub type Solution = Result<Answers, SolveError>;
+pub type Benchmark = Result<DayTimings, Box<dyn InputError>>;
+pub type Picture = Result<Image, Box<dyn InputError>>;
+// `None` when the day can't render in the requested format
+pub type Rendering = Result<Option<String>, Box<dyn InputError>>;
+
+/// How `aoc render` shows the way a day was solved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    Text,
+    Csv,
+    // Text with ANSI colours for a terminal
+    Colour,
+}
+
+impl fmt::Display for RenderFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderFormat::Text => write!(f, "text"),
+            RenderFormat::Csv => write!(f, "csv"),
+            RenderFormat::Colour => write!(f, "colour"),
+        }
+    }
+}
+
+pub struct Day {
+    pub solve: fn(&str, Option<Part>) -> Solution,
+    // Solve the day's `input_sample`, which for some days needs different
+    // settings or only has an answer to part 1
+    pub solve_sample: fn(&str, Option<Part>) -> Solution,
+    // Time parsing and every part separately, each for about the given time
+    pub bench: fn(&str, Duration) -> Benchmark,
+    pub has_part2: bool,
+    // A random but valid input from a seed and a size, see the day's
+    // generate module for what the size means
+    pub generate: fn(u64, usize) -> String,
+    pub default_size: usize,
+    // Only the days that are played out on a grid can draw an image
+    pub image: Option<fn(&str) -> Picture>,
+    // Only some days can show how they got to their answers
+    pub render: Option<fn(&str, RenderFormat) -> Rendering>,
+}
+
+fn solve_part<I, P: PartOutput>(
+    part: Part,
+    input: &I,
+    solve: impl FnOnce(&I) -> P,
+) -> Result<PartAnswer, SolveError> {
+    let start = Instant::now();
+    let answer = solve(input)
+        .into_result()
+        .map_err(|error| SolveError::Part { part, error })?;
+
+    Ok(PartAnswer {
+        part,
+        answer,
+        elapsed: start.elapsed(),
+    })
+}
+
+fn parse_input<'a, I, E: InputError + 'static>(
+    input: &'a str,
+    parse: fn(&'a str) -> Result<I, E>,
+) -> Result<I, SolveError> {
+    parse(input).map_err(|err| SolveError::Input(Box::new(err)))
+}
+
+// Parse `input` and solve the requested part, or both of them
+fn solve<'a, I, E: InputError + 'static, P1: PartOutput, P2: PartOutput>(
+    input: &'a str,
+    part: Option<Part>,
+    parse: fn(&'a str) -> Result<I, E>,
+    part1: fn(&I) -> P1,
+    part2: fn(&I) -> P2,
+) -> Solution {
+    let input = parse_input(input, parse)?;
+
+    let mut answers = Vec::new();
+    if part != Some(Part::Two) {
+        answers.push(solve_part(Part::One, &input, part1)?);
+    }
+    if part != Some(Part::One) {
+        answers.push(solve_part(Part::Two, &input, part2)?);
+    }
+    Ok(answers)
+}
+
+// Like `solve`, for a day or an input that only has a part 1. Asking for
+// part 2 gives no answers.
+fn solve_part1<'a, I, E: InputError + 'static, P1: PartOutput>(
+    input: &'a str,
+    part: Option<Part>,
+    parse: fn(&'a str) -> Result<I, E>,
+    part1: fn(&I) -> P1,
+) -> Solution {
+    let input = parse_input(input, parse)?;
+
+    if part == Some(Part::Two) {
+        return Ok(Vec::new());
+    }
+    Ok(vec![solve_part(Part::One, &input, part1)?])
+}
+
+macro_rules! day {
+    ($day:ident) => {
+        Day {
+            solve: |input, part| solve(input, part, $day::parse, $day::part1, $day::part2),
+            solve_sample: |input, part| solve(input, part, $day::parse, $day::part1, $day::part2),
+            bench: |input, budget| {
+                let (parse, input) = bench::measure(budget, || $day::parse(input));
+                let input = input.map_err(|err| Box::new(err) as Box<dyn InputError>)?;
+                let (part1, _) = bench::measure(budget, || $day::part1(&input));
+                let (part2, _) = bench::measure(budget, || $day::part2(&input));
+                Ok(DayTimings {
+                    parse,
+                    part1,
+                    part2: Some(part2),
+                })
+            },
+            has_part2: true,
+            generate: $day::generate::generate,
+            default_size: $day::generate::DEFAULT_SIZE,
+            image: None,
+            render: None,
+        }
+    };
+    ($day:ident, image) => {
+        Day {
+            image: Some(|input| {
+                let input =
+                    $day::parse(input).map_err(|err| Box::new(err) as Box<dyn InputError>)?;
+                Ok($day::image(&input))
+            }),
+            ..day!($day)
+        }
+    };
+    ($day:ident, no_part2) => {
+        Day {
+            solve: |input, part| solve_part1(input, part, $day::parse, $day::part1),
+            solve_sample: |input, part| solve_part1(input, part, $day::parse, $day::part1),
+            bench: |input, budget| {
+                let (parse, input) = bench::measure(budget, || $day::parse(input));
+                let input = input.map_err(|err| Box::new(err) as Box<dyn InputError>)?;
+                let (part1, _) = bench::measure(budget, || $day::part1(&input));
+                Ok(DayTimings {
+                    parse,
+                    part1,
+                    part2: None,
+                })
+            },
+            has_part2: false,
+            generate: $day::generate::generate,
+            default_size: $day::generate::DEFAULT_SIZE,
+            image: None,
+            render: None,
+        }
+    };
+}
+
+pub const DAYS: [Day; 25] = [
+    Day {
+        render: Some(|input, format| {
+            let input = day_1::parse(input).map_err(|err| Box::new(err) as Box<dyn InputError>)?;
+            Ok(match format {
+                RenderFormat::Csv => Some(day_1::metrics::pair_report(
+                    &input,
+                    &day_1::metrics::Metric::ALL,
+                )),
+                RenderFormat::Text | RenderFormat::Colour => None,
+            })
+        }),
+        ..day!(day_1)
+    },
+    Day {
+        render: Some(|input, format| {
+            let input = day_2::parse(input).map_err(|err| Box::new(err) as Box<dyn InputError>)?;
+            Ok(match format {
+                RenderFormat::Text => Some(day_2::diagnostics(&input)),
+                RenderFormat::Csv | RenderFormat::Colour => None,
+            })
+        }),
+        ..day!(day_2)
+    },
+    Day {
+        render: Some(|input, format| {
+            let input = day_3::parse(input).map_err(|err| Box::new(err) as Box<dyn InputError>)?;
+            // The interpreter of part 2, which also shows the effect of the
+            // switches
+            let interpreter = day_3::interpreter::Interpreter::part2();
+            let steps = interpreter.trace(&input.tokens);
+            Ok(match format {
+                RenderFormat::Text => Some(day_3::trace::listing(input.text, &steps)),
+                RenderFormat::Colour => Some(day_3::trace::highlight(input.text, &steps)),
+                RenderFormat::Csv => None,
+            })
+        }),
+        ..day!(day_3)
+    },
+    day!(day_4, image),
+    Day {
+        render: Some(|input, format| {
+            let input = day_5::parse(input).map_err(|err| Box::new(err) as Box<dyn InputError>)?;
+            Ok(match format {
+                RenderFormat::Text => Some(day_5::audit::report(&input)),
+                RenderFormat::Csv | RenderFormat::Colour => None,
+            })
+        }),
+        ..day!(day_5)
+    },
+    Day {
+        render: Some(|input, format| {
+            let input = day_6::parse(input).map_err(|err| Box::new(err) as Box<dyn InputError>)?;
+            Ok(Some(match format {
+                // The map, followed by a line for every guard
+                RenderFormat::Text => day_6::patrols(&input)
+                    .iter()
+                    .fold(day_6::render_patrols(&input) + "\n", |text, patrol| {
+                        text + "\n" + &patrol.to_string()
+                    }),
+                RenderFormat::Csv => day_6::states_csv(&input),
+                RenderFormat::Colour => return Ok(None),
+            }))
+        }),
+        ..day!(day_6, image)
+    },
+    day!(day_7),
+    day!(day_8, image),
+    day!(day_9),
+    day!(day_10, image),
+    day!(day_11),
+    day!(day_12, image),
+    day!(day_13),
+    // The sample has no christmas tree to search for
+    Day {
+        solve_sample: |input, part| solve_part1(input, part, day_14::parse_sample, day_14::part1),
+        ..day!(day_14, image)
+    },
+    day!(day_15, image),
+    day!(day_16, image),
+    // The sample of part 1 runs a different program than that of part 2
+    Day {
+        solve_sample: |input, part| solve_part1(input, part, day_17::parse, day_17::part1),
+        ..day!(day_17)
+    },
+    Day {
+        solve_sample: |input, part| {
+            solve(
+                input,
+                part,
+                day_18::parse_sample,
+                day_18::part1,
+                day_18::part2,
+            )
+        },
+        ..day!(day_18, image)
+    },
+    day!(day_19),
+    Day {
+        solve_sample: |input, part| {
+            solve(
+                input,
+                part,
+                day_20::parse_sample,
+                day_20::part1,
+                day_20::part2,
+            )
+        },
+        ..day!(day_20, image)
+    },
+    day!(day_21),
+    day!(day_22),
+    day!(day_23),
+    // The sample isn't an adder, so only the simulation applies
+    Day {
+        solve_sample: |input, part| solve_part1(input, part, day_24::parse, day_24::part1),
+        ..day!(day_24)
+    },
+    day!(day_25, no_part2),
+];
+
+/// The directory of a day's crate, which holds its puzzle inputs
+pub fn day_dir(day: usize) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day-{day}"))
+}
@@ -0,0 +1,511 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc::{bench, day_dir, json, Part, RenderFormat, SolveError, DAYS};
+use common::InputError;
+use grid::ImageFormat;
+
+const USAGE: &str =
+    "Usage: aoc run <day|all> [<input path>|-] [--sample] [--part <1|2>] [--format <text|json>]
+       aoc bench <day|all> [--time <ms per stage>] [--json <report path>]
+       aoc gen <day> [--seed <number>] [--size <number>]
+       aoc image <day> [<input path>|-] [--sample] [--out <.png|.ppm|.svg path>] [--scale <pixels per cell>]
+       aoc render <day> [<input path>|-] [--sample] [--format <text|csv|colour>]";
+
+// Where to read a day's puzzle input from
+#[derive(Debug, Clone, PartialEq)]
+enum Source {
+    // The `input` file in the day's directory
+    Default,
+    // The `input_sample` file in the day's directory
+    Sample,
+    Path(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    // One JSON object per line for every answer
+    Json,
+}
+
+#[derive(Debug)]
+struct RunArgs {
+    days: Vec<usize>,
+    part: Option<Part>,
+    source: Source,
+    format: Format,
+}
+
+#[derive(Debug)]
+struct BenchArgs {
+    days: Vec<usize>,
+    budget: Duration,
+    report_path: PathBuf,
+}
+
+#[derive(Debug)]
+struct GenArgs {
+    day: usize,
+    seed: u64,
+    // The day's default size when not given
+    size: Option<usize>,
+}
+
+#[derive(Debug)]
+struct ImageArgs {
+    day: usize,
+    source: Source,
+    out: PathBuf,
+    scale: usize,
+}
+
+#[derive(Debug)]
+struct RenderArgs {
+    day: usize,
+    source: Source,
+    format: RenderFormat,
+}
+
+#[derive(Debug)]
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Gen(GenArgs),
+    Image(ImageArgs),
+    Render(RenderArgs),
+}
+
+fn parse_days(arg: Option<&String>) -> Result<Vec<usize>, String> {
+    match arg.map(String::as_str) {
+        Some("all") => Ok((1..=DAYS.len()).collect()),
+        Some(day) => match day.parse::<usize>() {
+            Ok(day) if (1..=DAYS.len()).contains(&day) => Ok(vec![day]),
+            _ => Err(format!("Invalid day '{day}', expected 1-25 or 'all'")),
+        },
+        None => Err(String::from("Missing day")),
+    }
+}
+
+// `--sample`, `-` for stdin or a path, only one of which can be given
+fn parse_source(source: &mut Source, arg: &str) -> Result<(), String> {
+    if *source != Source::Default {
+        return Err(String::from("Only one input source can be given"));
+    }
+
+    *source = match arg {
+        "--sample" => Source::Sample,
+        "-" => Source::Stdin,
+        path => Source::Path(PathBuf::from(path)),
+    };
+    Ok(())
+}
+
+fn parse_run_args<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<RunArgs, String> {
+    let days = parse_days(args.next())?;
+
+    let mut part = None;
+    let mut source = Source::Default;
+    let mut format = Format::Text;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = match args.next().map(String::as_str) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some(format) => {
+                        return Err(format!("Invalid format '{format}', expected text or json"))
+                    }
+                    None => return Err(String::from("Missing value for --format")),
+                }
+            }
+            "--sample" => parse_source(&mut source, arg)?,
+            "--part" => {
+                part = match args.next().map(String::as_str) {
+                    Some("1") => Some(Part::One),
+                    Some("2") => Some(Part::Two),
+                    Some(part) => return Err(format!("Invalid part '{part}', expected 1 or 2")),
+                    None => return Err(String::from("Missing value for --part")),
+                }
+            }
+            arg if arg.starts_with("--") => return Err(format!("Unknown argument '{arg}'")),
+            arg => parse_source(&mut source, arg)?,
+        }
+    }
+
+    if days.len() > 1 && matches!(source, Source::Path(_) | Source::Stdin) {
+        return Err(String::from(
+            "An input path or stdin can only be used when running a single day",
+        ));
+    }
+
+    Ok(RunArgs {
+        days,
+        part,
+        source,
+        format,
+    })
+}
+
+fn parse_bench_args<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<BenchArgs, String> {
+    let days = parse_days(args.next())?;
+
+    let mut budget = Duration::from_millis(200);
+    let mut report_path = PathBuf::from("bench.json");
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--time" => {
+                budget = match args.next().map(|ms| (ms, ms.parse::<u64>())) {
+                    Some((_, Ok(ms))) => Duration::from_millis(ms),
+                    Some((ms, Err(_))) => {
+                        return Err(format!("Invalid time '{ms}', expected milliseconds"))
+                    }
+                    None => return Err(String::from("Missing value for --time")),
+                }
+            }
+            "--json" => {
+                report_path = match args.next() {
+                    Some(path) => PathBuf::from(path),
+                    None => return Err(String::from("Missing value for --json")),
+                }
+            }
+            arg => return Err(format!("Unknown argument '{arg}'")),
+        }
+    }
+
+    Ok(BenchArgs {
+        days,
+        budget,
+        report_path,
+    })
+}
+
+fn parse_gen_args<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<GenArgs, String> {
+    let day = match parse_days(args.next())?[..] {
+        [day] => day,
+        _ => {
+            return Err(String::from(
+                "Inputs can only be generated for a single day",
+            ))
+        }
+    };
+
+    let mut seed = 0;
+    let mut size = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                seed = match args.next().map(|seed| (seed, seed.parse())) {
+                    Some((_, Ok(seed))) => seed,
+                    Some((seed, Err(_))) => {
+                        return Err(format!("Invalid seed '{seed}', expected a number"))
+                    }
+                    None => return Err(String::from("Missing value for --seed")),
+                }
+            }
+            "--size" => {
+                size = match args.next().map(|size| (size, size.parse())) {
+                    Some((_, Ok(size))) => Some(size),
+                    Some((size, Err(_))) => {
+                        return Err(format!("Invalid size '{size}', expected a number"))
+                    }
+                    None => return Err(String::from("Missing value for --size")),
+                }
+            }
+            arg => return Err(format!("Unknown argument '{arg}'")),
+        }
+    }
+
+    Ok(GenArgs { day, seed, size })
+}
+
+fn parse_image_args<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<ImageArgs, String> {
+    let day = match parse_days(args.next())?[..] {
+        [day] => day,
+        _ => return Err(String::from("Images can only be drawn for a single day")),
+    };
+    if DAYS[day - 1].image.is_none() {
+        return Err(format!("Day {day} has no grid to draw"));
+    }
+
+    let mut source = Source::Default;
+    let mut out = PathBuf::from(format!("day-{day}.png"));
+    let mut scale = 4;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => {
+                out = match args.next() {
+                    Some(path) => PathBuf::from(path),
+                    None => return Err(String::from("Missing value for --out")),
+                }
+            }
+            "--scale" => {
+                scale = match args.next().map(|scale| (scale, scale.parse())) {
+                    Some((_, Ok(scale @ 1..))) => scale,
+                    Some((scale, _)) => {
+                        return Err(format!(
+                            "Invalid scale '{scale}', expected a positive number"
+                        ))
+                    }
+                    None => return Err(String::from("Missing value for --scale")),
+                }
+            }
+            "--sample" => parse_source(&mut source, arg)?,
+            arg if arg.starts_with("--") => return Err(format!("Unknown argument '{arg}'")),
+            arg => parse_source(&mut source, arg)?,
+        }
+    }
+
+    if ImageFormat::from_path(&out).is_none() {
+        return Err(format!(
+            "Unknown image format for {}, expected a .png, .ppm or .svg file",
+            out.display()
+        ));
+    }
+
+    Ok(ImageArgs {
+        day,
+        source,
+        out,
+        scale,
+    })
+}
+
+fn parse_render_args<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<RenderArgs, String> {
+    let day = match parse_days(args.next())?[..] {
+        [day] => day,
+        _ => return Err(String::from("Only a single day can be rendered")),
+    };
+    if DAYS[day - 1].render.is_none() {
+        return Err(format!("Day {day} cannot be rendered"));
+    }
+
+    let mut source = Source::Default;
+    let mut format = RenderFormat::Text;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = match args.next().map(String::as_str) {
+                    Some("text") => RenderFormat::Text,
+                    Some("csv") => RenderFormat::Csv,
+                    Some("colour") => RenderFormat::Colour,
+                    Some(format) => {
+                        return Err(format!(
+                            "Invalid format '{format}', expected text, csv or colour"
+                        ))
+                    }
+                    None => return Err(String::from("Missing value for --format")),
+                }
+            }
+            "--sample" => parse_source(&mut source, arg)?,
+            arg if arg.starts_with("--") => return Err(format!("Unknown argument '{arg}'")),
+            arg => parse_source(&mut source, arg)?,
+        }
+    }
+
+    Ok(RenderArgs {
+        day,
+        source,
+        format,
+    })
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+
+    match args.next().map(String::as_str) {
+        Some("run") => parse_run_args(args).map(Command::Run),
+        Some("bench") => parse_bench_args(args).map(Command::Bench),
+        Some("gen") => parse_gen_args(args).map(Command::Gen),
+        Some("image") => parse_image_args(args).map(Command::Image),
+        Some("render") => parse_render_args(args).map(Command::Render),
+        Some(command) => Err(format!("Unknown command '{command}'")),
+        None => Err(String::from("Missing command")),
+    }
+}
+
+fn read_input(day: usize, source: &Source) -> Result<String, String> {
+    let day_dir = day_dir(day);
+
+    let input_path = match source {
+        Source::Default => day_dir.join("input"),
+        Source::Sample => day_dir.join("input_sample"),
+        Source::Path(path) => path.clone(),
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| format!("Could not read stdin: {err}"))?;
+            return Ok(input);
+        }
+    };
+
+    if *source == Source::Sample && !input_path.exists() {
+        return Err(format!("Day {day} has no input_sample file"));
+    }
+
+    fs::read_to_string(&input_path)
+        .map_err(|err| format!("Could not read {}: {err}", input_path.display()))
+}
+
+// Describe a parse error together with the offending line of the input
+fn diagnostic(input: &str, err: &dyn InputError) -> String {
+    let location = err.location();
+    let line = input.lines().nth(location.line - 1).unwrap_or("");
+    let gutter = " ".repeat(location.line.to_string().len());
+
+    format!(
+        "Invalid input at {location}: {err}\n{gutter} |\n{} | {line}\n{gutter} | {}^",
+        location.line,
+        " ".repeat(location.column - 1)
+    )
+}
+
+fn run_day(day: usize, part: Option<Part>, source: &Source, format: Format) -> Result<(), String> {
+    let solution = &DAYS[day - 1];
+    if part == Some(Part::Two) && !solution.has_part2 {
+        return Err(format!("Day {day} has no part 2"));
+    }
+
+    let input = read_input(day, source)?;
+
+    if format == Format::Text {
+        println!("Day {day}");
+    }
+
+    let solve = if *source == Source::Sample {
+        solution.solve_sample
+    } else {
+        solution.solve
+    };
+    let answers = solve(&input, part).map_err(|err| match err {
+        SolveError::Input(err) => diagnostic(&input, &*err),
+        SolveError::Part { part, error } => {
+            format!("Day {day} part {part} has no answer for this input: {error}")
+        }
+    })?;
+    if answers.is_empty() {
+        return Err(format!("Day {day} part 2 has no answer for the sample"));
+    }
+    for answer in answers {
+        match format {
+            Format::Text => println!("Part {}: {}", answer.part, answer.answer),
+            Format::Json => println!(
+                "{{\"day\": {day}, \"part\": {}, \"answer\": {}, \"elapsed_ms\": {:.3}}}",
+                answer.part,
+                json::answer(&answer.answer),
+                answer.elapsed.as_secs_f64() * 1000.0
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    if cfg!(debug_assertions) {
+        eprintln!("Warning: timing a debug build, run with --release for meaningful numbers");
+    }
+
+    let mut results = Vec::new();
+    for &day in &args.days {
+        let input = read_input(day, &Source::Default)?;
+        let timings = (DAYS[day - 1].bench)(&input, args.budget)
+            .map_err(|err| format!("Day {day}\n{}", diagnostic(&input, &*err)))?;
+        results.push((day, timings));
+    }
+
+    println!("{}", bench::table(&results));
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    fs::write(&args.report_path, bench::json(&results, timestamp))
+        .map_err(|err| format!("Could not write {}: {err}", args.report_path.display()))?;
+    println!("\nWrote JSON report to {}", args.report_path.display());
+
+    Ok(())
+}
+
+fn generate(args: &GenArgs) {
+    let day = &DAYS[args.day - 1];
+    print!(
+        "{}",
+        (day.generate)(args.seed, args.size.unwrap_or(day.default_size))
+    );
+}
+
+fn draw(args: &ImageArgs) -> Result<(), String> {
+    let input = read_input(args.day, &args.source)?;
+    let image = (DAYS[args.day - 1]
+        .image
+        .expect("only days with an image are accepted"))(&input)
+    .map_err(|err| diagnostic(&input, &*err))?;
+
+    image
+        .save(&args.out, args.scale)
+        .map_err(|err| format!("Could not write {}: {err}", args.out.display()))?;
+    println!("Wrote {}", args.out.display());
+
+    Ok(())
+}
+
+fn render(args: &RenderArgs) -> Result<(), String> {
+    let input = read_input(args.day, &args.source)?;
+    let rendering = (DAYS[args.day - 1]
+        .render
+        .expect("only days that can render are accepted"))(&input, args.format)
+    .map_err(|err| diagnostic(&input, &*err))?;
+
+    match rendering {
+        Some(rendering) => {
+            println!("{}", rendering.trim_end_matches('\n'));
+            Ok(())
+        }
+        None => Err(format!(
+            "Day {} cannot be rendered as {}",
+            args.day, args.format
+        )),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("{err}");
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match command {
+        Command::Run(args) => args
+            .days
+            .iter()
+            .try_for_each(|day| run_day(*day, args.part, &args.source, args.format)),
+        Command::Bench(args) => bench(&args),
+        Command::Gen(args) => {
+            generate(&args);
+            Ok(())
+        }
+        Command::Image(args) => draw(&args),
+        Command::Render(args) => render(&args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use aoc::{Part, DAYS};
+
+// A size per day that keeps the test quick in a debug build
+const SIZES: [usize; 25] = [
+    100, 100, 2000, 20, 20, 20, 50, 20, 999, 20, 8, 30, 20, 500, 20, 21, 9, 3450, 20, 41, 5, 10,
+    60, 45, 50,
+];
+
+// Every generator has to give the same input for the same seed, a different
+// one for another seed, and an input that parses and has an answer to every
+// part of the day
+#[test]
+fn generated_inputs() {
+    let mut failures = Vec::new();
+
+    for (day, solution) in DAYS.iter().enumerate().map(|(i, day)| (i + 1, day)) {
+        let size = SIZES[day - 1];
+        let input = (solution.generate)(1, size);
+
+        if (solution.generate)(1, size) != input {
+            failures.push(format!("day {day}: seed 1 gave two different inputs"));
+        }
+        if (solution.generate)(2, size) == input {
+            failures.push(format!("day {day}: seeds 1 and 2 gave the same input"));
+        }
+
+        match (solution.solve)(&input, None) {
+            Ok(answers) => {
+                let parts = answers.iter().map(|answer| answer.part).collect::<Vec<_>>();
+                let expected = if solution.has_part2 {
+                    vec![Part::One, Part::Two]
+                } else {
+                    vec![Part::One]
+                };
+                if parts != expected {
+                    failures.push(format!("day {day}: answered parts {parts:?}"));
+                }
+            }
+            Err(err) => failures.push(format!("day {day}: {err}")),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} generated input failure(s):\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,51 @@
+use std::fs;
+
+use aoc::{day_dir, golden, Part, DAYS};
+use common::Answer;
+
+// Runs every day on its real input and compares with the answers in its
+// answers.toml, reporting all mismatches at once
+#[test]
+fn golden_answers() {
+    let mut mismatches = Vec::new();
+
+    for (day, solution) in DAYS.iter().enumerate().map(|(i, day)| (i + 1, day)) {
+        let input_path = day_dir(day).join("input");
+        let input = fs::read_to_string(&input_path)
+            .unwrap_or_else(|err| panic!("Could not read {}: {err}", input_path.display()));
+        let expected = golden::load(day).unwrap();
+
+        let answers = match (solution.solve)(&input, None) {
+            Ok(answers) => answers,
+            Err(err) => {
+                mismatches.push(format!("day {day}: {err}"));
+                continue;
+            }
+        };
+
+        for part in [Part::One, Part::Two] {
+            let actual = answers
+                .iter()
+                .find(|answer| answer.part == part)
+                .map(|answer| &answer.answer);
+            if actual != expected.get(part) {
+                let show = |answer: Option<&Answer>| {
+                    answer.map_or(String::from("<none>"), |answer| answer.to_string())
+                };
+                mismatches.push(format!(
+                    "day {day} part {}\n  - expected: {}\n  + actual:   {}",
+                    part,
+                    show(expected.get(part)),
+                    show(actual)
+                ));
+            }
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "{} golden answer mismatch(es):\n{}",
+        mismatches.len(),
+        mismatches.join("\n")
+    );
+}
@@ -0,0 +1,37 @@
+use std::fs;
+
+use aoc::{day_dir, DAYS};
+
+// Every day has an `input_sample` for `aoc run --sample`, which has to parse
+// and have an answer to at least part 1
+#[test]
+fn samples() {
+    let mut failures = Vec::new();
+
+    for (day, solution) in DAYS.iter().enumerate().map(|(i, day)| (i + 1, day)) {
+        let input_path = day_dir(day).join("input_sample");
+        let input = match fs::read_to_string(&input_path) {
+            Ok(input) => input,
+            Err(err) => {
+                failures.push(format!(
+                    "day {day}: could not read {}: {err}",
+                    input_path.display()
+                ));
+                continue;
+            }
+        };
+
+        match (solution.solve_sample)(&input, None) {
+            Ok(answers) if answers.is_empty() => failures.push(format!("day {day}: no answers")),
+            Ok(_) => {}
+            Err(err) => failures.push(format!("day {day}: {err}")),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} sample failure(s):\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
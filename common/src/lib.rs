@@ -0,0 +1,115 @@
+use std::{error::Error, fmt};
+
+pub use rng::Rng;
+
+mod rng;
+
+/// The answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(String::from(value))
+    }
+}
+
+/// A position in the puzzle input, both line and column start at 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize) -> Location {
+        Location { line, column }
+    }
+
+    /// The location of `fragment` within `input`, `fragment` has to be a
+    /// slice of `input` as returned by `lines()`, `split()` and the like.
+    pub fn of(input: &str, fragment: &str) -> Location {
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(offset <= input.len(), "fragment is not part of the input");
+
+        Location::at_offset(input, offset)
+    }
+
+    /// The location just after the last character of `input`
+    pub fn end_of(input: &str) -> Location {
+        Location::at_offset(input, input.len())
+    }
+
+    fn at_offset(input: &str, offset: usize) -> Location {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// An error in the puzzle input that points to where it went wrong
+pub trait InputError: Error {
+    fn location(&self) -> Location;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location_of_fragment() {
+        let input = "12 34\n56 7x\n";
+        let fragment = input.lines().nth(1).unwrap().split(' ').nth(1).unwrap();
+
+        assert_eq!(Location::of(input, fragment), Location::new(2, 4));
+        assert_eq!(Location::of(input, input), Location::new(1, 1));
+        assert_eq!(Location::end_of(input), Location::new(3, 1));
+        assert_eq!(Location::end_of("ab"), Location::new(1, 3));
+    }
+}
@@ -0,0 +1,83 @@
+/// A small seeded pseudo random number generator (SplitMix64). It is not
+/// suitable for anything but generating puzzle inputs, but the same seed
+/// always gives the same input.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` must not be zero
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "cannot pick a number below zero");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// A number in `low..=high`
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "empty range {low}..={high}");
+        let span = high.abs_diff(low);
+        if span == u64::MAX {
+            return self.next_u64() as i64;
+        }
+        low.wrapping_add(self.below(span + 1) as i64)
+    }
+
+    /// An index into a collection of `len` items
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..10).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn numbers_stay_in_range() {
+        let mut rng = Rng::new(0);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-2..=2).contains(&rng.between(-2, 2)));
+        }
+        assert_eq!(rng.between(i64::MIN, i64::MIN), i64::MIN);
+    }
+}
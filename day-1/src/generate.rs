@@ -0,0 +1,28 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// Two location lists of `size` lines, part of the right list is taken from
+/// the left one so the similarity score isn't always zero
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let list_a = (0..size)
+        .map(|_| rng.between(10000, 99999))
+        .collect::<Vec<_>>();
+    let list_b = (0..size)
+        .map(|_| {
+            if !list_a.is_empty() && rng.chance(0.3) {
+                *rng.choose(&list_a)
+            } else {
+                rng.between(10000, 99999)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    list_a
+        .iter()
+        .zip(&list_b)
+        .map(|(a, b)| format!("{a}   {b}\n"))
+        .collect()
+}
@@ -0,0 +1,164 @@
+use std::{error::Error, fmt, iter::zip};
+
+use common::{Answer, InputError, Location};
+use metrics::{Metric, Score};
+
+pub mod generate;
+pub mod metrics;
+pub mod stream;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    ExpectedNumber { location: Location, found: String },
+    MissingNumber { location: Location },
+    TrailingInput { location: Location, found: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::ExpectedNumber { found, .. } => {
+                write!(f, "expected a number, found {found:?}")
+            }
+            ParseError::MissingNumber { .. } => {
+                write!(f, "expected a second number, found the end of the line")
+            }
+            ParseError::TrailingInput { found, .. } => {
+                write!(f, "expected the end of the line, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::ExpectedNumber { location, .. }
+            | ParseError::MissingNumber { location }
+            | ParseError::TrailingInput { location, .. } => *location,
+        }
+    }
+}
+
+// Both lists are kept sorted
+pub struct Input {
+    list_a: Vec<u32>,
+    list_b: Vec<u32>,
+}
+
+impl Input {
+    /// The smallest number of the left list with the smallest of the right
+    /// list, and so on
+    pub fn pairs(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        zip(self.list_a.iter().copied(), self.list_b.iter().copied())
+    }
+}
+
+fn parse_line(input: &str, line: &str) -> Result<(u32, u32), ParseError> {
+    let mut numbers_it = line.split_whitespace().map(|str_number| {
+        str_number
+            .parse::<u32>()
+            .map_err(|_| ParseError::ExpectedNumber {
+                location: Location::of(input, str_number),
+                found: String::from(str_number),
+            })
+    });
+
+    let missing = || ParseError::MissingNumber {
+        location: Location::of(input, &line[line.len()..]),
+    };
+    let a = numbers_it.next().ok_or_else(missing)??;
+    let b = numbers_it.next().ok_or_else(missing)??;
+
+    if let Some(trailing) = line.split_whitespace().nth(2) {
+        return Err(ParseError::TrailingInput {
+            location: Location::of(input, trailing),
+            found: String::from(trailing),
+        });
+    }
+
+    Ok((a, b))
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut list_a = Vec::new();
+    let mut list_b = Vec::new();
+
+    for line in input.lines() {
+        let (a, b) = parse_line(input, line)?;
+        list_a.push(a);
+        list_b.push(b);
+    }
+
+    list_a.sort_unstable();
+    list_b.sort_unstable();
+
+    Ok(Input { list_a, list_b })
+}
+
+fn whole(metric: Metric, input: &Input) -> Answer {
+    match metric.score(input) {
+        Score::Whole(score) => match u64::try_from(score) {
+            Ok(score) => score.into(),
+            Err(_) => score.to_string().into(),
+        },
+        Score::Real(_) => unreachable!("{} is not a whole number", metric.name()),
+    }
+}
+
+pub fn part1(input: &Input) -> Answer {
+    whole(Metric::L1, input)
+}
+
+pub fn part2(input: &Input) -> Answer {
+    whole(Metric::Similarity, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input() {
+        let input = parse(include_str!("../input_sample")).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(11));
+        assert_eq!(part2(&input), Answer::Number(31));
+    }
+
+    #[test]
+    fn pairs_in_sorted_order() {
+        // The smallest number of one list goes with the smallest of the
+        // other, whatever order the lists are in
+        let input = parse("5   1\n4   2\n3   3\n2   4\n1   5").unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(0));
+    }
+
+    #[test]
+    fn similarity_above_u64() {
+        let input = parse(&"4000000000   4000000000\n".repeat(300_000)).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(0));
+        assert_eq!(part2(&input), Answer::from("360000000000000000000"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse("1   2\n3   x4").err(),
+            Some(ParseError::ExpectedNumber {
+                location: Location::new(2, 5),
+                found: String::from("x4"),
+            })
+        );
+        assert_eq!(
+            parse("1   2\n3").err(),
+            Some(ParseError::MissingNumber {
+                location: Location::new(2, 2),
+            })
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crate::Input;
+
+/// The ways two location lists can be compared. The pairwise metrics pair
+/// the smallest number of one list with the smallest of the other and so
+/// on, like part 1 of the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// The sum of the distances within each pair, part 1 of the puzzle
+    L1,
+    /// The euclidean distance between both sorted lists
+    L2,
+    /// The largest distance within a pair
+    MaxDeviation,
+    /// How many distinct numbers appear in both lists out of all numbers
+    Jaccard,
+    /// Each number in the left list multiplied by how often it appears in
+    /// the right list, part 2 of the puzzle
+    Similarity,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 5] = [
+        Metric::L1,
+        Metric::L2,
+        Metric::MaxDeviation,
+        Metric::Jaccard,
+        Metric::Similarity,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::L1 => "l1",
+            Metric::L2 => "l2",
+            Metric::MaxDeviation => "max_deviation",
+            Metric::Jaccard => "jaccard",
+            Metric::Similarity => "similarity",
+        }
+    }
+
+    /// What a single pair adds to the score, `None` for Jaccard as it
+    /// compares the lists as sets
+    pub fn contribution(self, (a, b): (u32, u32), counts_b: &HashMap<u32, u64>) -> Option<u64> {
+        let distance = a.abs_diff(b) as u64;
+
+        match self {
+            Metric::L1 | Metric::MaxDeviation => Some(distance),
+            Metric::L2 => Some(distance * distance),
+            Metric::Jaccard => None,
+            Metric::Similarity => Some(a as u64 * counts_b.get(&a).copied().unwrap_or(0)),
+        }
+    }
+
+    // A single contribution fits in a u64, but their sum may not
+    pub fn score(self, input: &Input) -> Score {
+        let counts_b = occurrences(&[self], &input.list_b);
+        let contributions = input
+            .pairs()
+            .filter_map(|pair| self.contribution(pair, &counts_b))
+            .map(u128::from);
+
+        match self {
+            Metric::L1 | Metric::Similarity => Score::Whole(contributions.sum()),
+            Metric::L2 => Score::Real((contributions.sum::<u128>() as f64).sqrt()),
+            Metric::MaxDeviation => Score::Whole(contributions.max().unwrap_or(0)),
+            Metric::Jaccard => {
+                let set_a = input.list_a.iter().collect::<HashSet<_>>();
+                let set_b = input.list_b.iter().collect::<HashSet<_>>();
+                let union = set_a.union(&set_b).count();
+                if union == 0 {
+                    Score::Real(1.0)
+                } else {
+                    Score::Real(set_a.intersection(&set_b).count() as f64 / union as f64)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Score {
+    Whole(u128),
+    Real(f64),
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Score::Whole(n) => write!(f, "{n}"),
+            Score::Real(x) => write!(f, "{x:.6}"),
+        }
+    }
+}
+
+// How often each number is in `list`, only counted when one of `metrics`
+// needs it
+fn occurrences(metrics: &[Metric], list: &[u32]) -> HashMap<u32, u64> {
+    if !metrics.contains(&Metric::Similarity) {
+        return HashMap::new();
+    }
+
+    list.iter().fold(HashMap::new(), |mut acc, n| {
+        *acc.entry(*n).or_default() += 1;
+        acc
+    })
+}
+
+/// A CSV with every sorted pair and what it contributes to each of the
+/// given metrics, so the pairs that drive a score can be found
+pub fn pair_report(input: &Input, metrics: &[Metric]) -> String {
+    let counts_b = occurrences(metrics, &input.list_b);
+
+    let mut report = String::from("left,right");
+    for metric in metrics {
+        report.push(',');
+        report.push_str(metric.name());
+    }
+    report.push('\n');
+
+    for pair in input.pairs() {
+        report.push_str(&format!("{},{}", pair.0, pair.1));
+        for metric in metrics {
+            report.push(',');
+            if let Some(contribution) = metric.contribution(pair, &counts_b) {
+                report.push_str(&contribution.to_string());
+            }
+        }
+        report.push('\n');
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn sample_metrics() {
+        let input = parse(include_str!("../input_sample")).unwrap();
+        let scores = Metric::ALL.map(|metric| metric.score(&input));
+
+        // Distances 2, 1, 0, 1, 2, 5 and the sets {1, 2, 3, 4} and
+        // {3, 4, 5, 9}
+        assert_eq!(
+            scores,
+            [
+                Score::Whole(11),
+                Score::Real(35f64.sqrt()),
+                Score::Whole(5),
+                Score::Real(2.0 / 6.0),
+                Score::Whole(31),
+            ]
+        );
+    }
+
+    #[test]
+    fn sums_above_u64() {
+        let input = parse(&"4000000000   4000000000\n".repeat(300_000)).unwrap();
+        assert_eq!(
+            Metric::Similarity.score(&input),
+            Score::Whole(360_000_000_000_000_000_000)
+        );
+
+        let input = parse("0   4000000000\n0   4000000000\n").unwrap();
+        assert_eq!(
+            Metric::L2.score(&input),
+            Score::Real(32_000_000_000_000_000_000f64.sqrt())
+        );
+    }
+
+    #[test]
+    fn sample_report() {
+        let input = parse(include_str!("../input_sample")).unwrap();
+
+        assert_eq!(
+            pair_report(&input, &[Metric::L1, Metric::Jaccard, Metric::Similarity]),
+            "left,right,l1,jaccard,similarity
+1,3,2,,0
+2,3,1,,0
+3,3,0,,9
+3,4,1,,9
+3,5,2,,9
+4,9,5,,4
+"
+        );
+    }
+}
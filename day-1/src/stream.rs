@@ -0,0 +1,522 @@
+//! Comparing location lists that don't fit in memory. Both columns are read
+//! line by line from any `BufRead` and sorted externally: numbers are
+//! collected in chunks, and every chunk that exceeds the memory limit is
+//! sorted and spilled to a temporary file. The sorted runs are merged in
+//! passes until few enough are left to read at once, then both lists are
+//! walked through together a single time for both scores.
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    env,
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use common::{InputError, Location};
+
+use crate::{parse_line, ParseError};
+
+// Every spilled run gets its own file name, also when several comparisons
+// run at the same time
+static NEXT_RUN: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamConfig {
+    /// Roughly how many bytes of numbers are kept in memory for both lists
+    /// together before they are spilled to disk
+    pub memory_limit: usize,
+    /// Where the sorted runs are spilled to
+    pub temp_dir: PathBuf,
+    /// How many sorted runs of a list are read at once, so how many files
+    /// are open per list. More runs are first merged in several passes.
+    pub merge_fan_in: usize,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        StreamConfig {
+            memory_limit: 64 * 1024 * 1024,
+            temp_dir: env::temp_dir(),
+            merge_fan_in: 64,
+        }
+    }
+}
+
+/// Part 1 and part 2 of the puzzle for two lists of `pairs` numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparison {
+    pub pairs: u64,
+    pub distance: u64,
+    pub similarity: u64,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+    MismatchedLengths { left: u64, right: u64 },
+    // `total` is "distance" or "similarity"
+    Overflow { total: &'static str },
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "{err}"),
+            StreamError::Parse(err) => write!(f, "{}: {err}", err.location()),
+            StreamError::MismatchedLengths { left, right } => write!(
+                f,
+                "the lists differ in length, the left one has {left} numbers and the right one {right}"
+            ),
+            StreamError::Overflow { total } => write!(f, "the {total} doesn't fit in 64 bits"),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(err) => Some(err),
+            StreamError::Parse(err) => Some(err),
+            StreamError::MismatchedLengths { .. } | StreamError::Overflow { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+// A sorted run on disk as little endian numbers, removed again when dropped
+struct SpilledRun {
+    path: PathBuf,
+}
+
+impl SpilledRun {
+    fn write(
+        dir: &Path,
+        numbers: impl IntoIterator<Item = io::Result<u32>>,
+        buffer_len: usize,
+    ) -> io::Result<SpilledRun> {
+        let run = NEXT_RUN.fetch_add(1, Ordering::Relaxed);
+        let path = dir.join(format!("day-1-{}-{run}.run", process::id()));
+
+        let mut writer = BufWriter::with_capacity(buffer_len, File::create(&path)?);
+        let run = SpilledRun { path };
+        for number in numbers {
+            writer.write_all(&number?.to_le_bytes())?;
+        }
+        writer.flush()?;
+
+        Ok(run)
+    }
+
+    fn read(&self, buffer_len: usize) -> io::Result<impl Iterator<Item = io::Result<u32>>> {
+        let mut reader = BufReader::with_capacity(buffer_len, File::open(&self.path)?);
+
+        Ok(std::iter::from_fn(move || {
+            let mut bytes = [0; 4];
+            match reader.read_exact(&mut bytes) {
+                Ok(()) => Some(Ok(u32::from_le_bytes(bytes))),
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => None,
+                Err(err) => Some(Err(err)),
+            }
+        }))
+    }
+}
+
+impl Drop for SpilledRun {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+// One list, sorted in runs
+struct ExternalSort<'a> {
+    config: &'a StreamConfig,
+    chunk_len: usize,
+    // The size of the buffer of every run that is read or written
+    buffer_len: usize,
+    chunk: Vec<u32>,
+    spilled: Vec<SpilledRun>,
+    len: u64,
+}
+
+impl<'a> ExternalSort<'a> {
+    fn new(config: &'a StreamConfig, chunk_len: usize, buffer_len: usize) -> ExternalSort<'a> {
+        ExternalSort {
+            config,
+            chunk_len,
+            buffer_len,
+            chunk: Vec::new(),
+            spilled: Vec::new(),
+            len: 0,
+        }
+    }
+
+    fn spill_chunk(&mut self) -> io::Result<()> {
+        self.chunk.sort_unstable();
+        let numbers = self.chunk.iter().copied().map(Ok);
+        self.spilled.push(SpilledRun::write(
+            &self.config.temp_dir,
+            numbers,
+            self.buffer_len,
+        )?);
+        self.chunk.clear();
+
+        Ok(())
+    }
+
+    fn push(&mut self, number: u32) -> io::Result<()> {
+        self.chunk.push(number);
+        self.len += 1;
+
+        if self.chunk.len() >= self.chunk_len {
+            self.spill_chunk()?;
+        }
+
+        Ok(())
+    }
+
+    // A list that fits in memory stays there. Otherwise the last chunk is
+    // spilled as well, to leave the memory to the buffers of the runs, and
+    // the runs are merged in groups until at most `merge_fan_in` are left.
+    fn finish(mut self) -> io::Result<SortedList> {
+        if self.spilled.is_empty() {
+            self.chunk.sort_unstable();
+            return Ok(SortedList {
+                in_memory: self.chunk,
+                spilled: Vec::new(),
+                buffer_len: self.buffer_len,
+            });
+        }
+
+        if !self.chunk.is_empty() {
+            self.spill_chunk()?;
+        }
+        self.chunk = Vec::new();
+
+        let fan_in = self.config.merge_fan_in.max(2);
+        while self.spilled.len() > fan_in {
+            let mut runs = std::mem::take(&mut self.spilled).into_iter();
+            loop {
+                let group = runs.by_ref().take(fan_in).collect::<Vec<_>>();
+                if group.is_empty() {
+                    break;
+                }
+
+                let readers = group
+                    .iter()
+                    .map(|run| Ok(Box::new(run.read(self.buffer_len)?) as Run))
+                    .collect::<io::Result<Vec<_>>>()?;
+                self.spilled.push(SpilledRun::write(
+                    &self.config.temp_dir,
+                    Merge::new(readers)?,
+                    self.buffer_len,
+                )?);
+            }
+        }
+
+        Ok(SortedList {
+            in_memory: Vec::new(),
+            spilled: self.spilled,
+            buffer_len: self.buffer_len,
+        })
+    }
+}
+
+struct SortedList {
+    in_memory: Vec<u32>,
+    spilled: Vec<SpilledRun>,
+    buffer_len: usize,
+}
+
+type Run<'a> = Box<dyn Iterator<Item = io::Result<u32>> + 'a>;
+
+impl SortedList {
+    // All numbers in ascending order, merged from the sorted runs
+    fn iter(&self) -> io::Result<Merge<'_>> {
+        let mut runs: Vec<Run> = vec![Box::new(self.in_memory.iter().copied().map(Ok))];
+        for run in &self.spilled {
+            runs.push(Box::new(run.read(self.buffer_len)?));
+        }
+
+        Merge::new(runs)
+    }
+}
+
+struct Merge<'a> {
+    runs: Vec<Run<'a>>,
+    heads: BinaryHeap<Reverse<(u32, usize)>>,
+}
+
+impl<'a> Merge<'a> {
+    fn new(mut runs: Vec<Run<'a>>) -> io::Result<Merge<'a>> {
+        let mut heads = BinaryHeap::new();
+        for (i, run) in runs.iter_mut().enumerate() {
+            if let Some(number) = run.next().transpose()? {
+                heads.push(Reverse((number, i)));
+            }
+        }
+
+        Ok(Merge { runs, heads })
+    }
+}
+
+impl Iterator for Merge<'_> {
+    type Item = io::Result<u32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((number, run)) = self.heads.pop()?;
+        match self.runs[run].next() {
+            Some(Ok(next)) => self.heads.push(Reverse((next, run))),
+            Some(Err(err)) => return Some(Err(err)),
+            None => {}
+        }
+
+        Some(Ok(number))
+    }
+}
+
+// Like `parse_line`, but a line may hold just one number when one list is
+// longer than the other. A line that starts with whitespace belongs to the
+// right list, otherwise to the left one.
+fn parse_stream_line(line: &str) -> Result<(Option<u32>, Option<u32>), ParseError> {
+    let numbers = line.split_whitespace().collect::<Vec<_>>();
+    if numbers.len() == 1 {
+        let number = numbers[0]
+            .parse::<u32>()
+            .map_err(|_| ParseError::ExpectedNumber {
+                location: Location::of(line, numbers[0]),
+                found: String::from(numbers[0]),
+            })?;
+        return Ok(if line.starts_with(char::is_whitespace) {
+            (None, Some(number))
+        } else {
+            (Some(number), None)
+        });
+    }
+
+    let (a, b) = parse_line(line, line)?;
+    Ok((Some(a), Some(b)))
+}
+
+fn on_line(err: ParseError, line: usize) -> ParseError {
+    let at = |location: Location| Location::new(line, location.column);
+
+    match err {
+        ParseError::ExpectedNumber { location, found } => ParseError::ExpectedNumber {
+            location: at(location),
+            found,
+        },
+        ParseError::MissingNumber { location } => ParseError::MissingNumber {
+            location: at(location),
+        },
+        ParseError::TrailingInput { location, found } => ParseError::TrailingInput {
+            location: at(location),
+            found,
+        },
+    }
+}
+
+/// Compare the two lists in `reader` without holding them in memory
+pub fn compare(reader: impl BufRead, config: &StreamConfig) -> Result<Comparison, StreamError> {
+    let chunk_len = (config.memory_limit / 2 / size_of::<u32>()).max(1);
+    // While merging at most `merge_fan_in` runs of both lists are read, or
+    // that many runs of one list are merged into a new one
+    let buffer_len =
+        (config.memory_limit / 2 / (config.merge_fan_in + 1)).clamp(size_of::<u32>(), 64 * 1024);
+    let mut list_a = ExternalSort::new(config, chunk_len, buffer_len);
+    let mut list_b = ExternalSort::new(config, chunk_len, buffer_len);
+
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (a, b) = parse_stream_line(&line).map_err(|err| on_line(err, number + 1))?;
+        if let Some(a) = a {
+            list_a.push(a)?;
+        }
+        if let Some(b) = b {
+            list_b.push(b)?;
+        }
+    }
+
+    if list_a.len != list_b.len {
+        return Err(StreamError::MismatchedLengths {
+            left: list_a.len,
+            right: list_b.len,
+        });
+    }
+    let pairs = list_a.len;
+    let list_a = list_a.finish()?;
+    let list_b = list_b.finish()?;
+
+    // Both lists are walked through together in order of value, rather than
+    // pair by pair. The distance of the sorted pairs is then the area
+    // between how many numbers of either list are at most some value: for
+    // every step up from one value to the next, every number one list is
+    // ahead by is paired with a number on the other side of the step.
+    let mut distance = 0u64;
+    let mut similarity = 0u64;
+    let mut numbers_a = list_a.iter()?;
+    let mut numbers_b = list_b.iter()?;
+    let mut a = numbers_a.next().transpose()?;
+    let mut b = numbers_b.next().transpose()?;
+    let mut previous: Option<(u32, i64)> = None;
+    let overflow = |total| StreamError::Overflow { total };
+    while let Some(value) = a.into_iter().chain(b).min() {
+        let mut ahead = match previous {
+            Some((previous, ahead)) => {
+                distance = ahead
+                    .unsigned_abs()
+                    .checked_mul((value - previous) as u64)
+                    .and_then(|area| distance.checked_add(area))
+                    .ok_or_else(|| overflow("distance"))?;
+                ahead
+            }
+            None => 0,
+        };
+
+        let (mut count_a, mut count_b) = (0, 0);
+        while a == Some(value) {
+            count_a += 1;
+            a = numbers_a.next().transpose()?;
+        }
+        while b == Some(value) {
+            count_b += 1;
+            b = numbers_b.next().transpose()?;
+        }
+
+        similarity = (value as u64)
+            .checked_mul(count_a)
+            .and_then(|score| score.checked_mul(count_b))
+            .and_then(|score| similarity.checked_add(score))
+            .ok_or_else(|| overflow("similarity"))?;
+        ahead += count_a as i64 - count_b as i64;
+        previous = Some((value, ahead));
+    }
+
+    Ok(Comparison {
+        pairs,
+        distance,
+        similarity,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, parse, part1, part2};
+    use common::Answer;
+
+    // A temp dir of its own per test, so it can be checked for leftovers
+    fn config(name: &str, memory_limit: usize) -> StreamConfig {
+        let temp_dir = env::temp_dir().join(format!("day-1-{}-{name}", process::id()));
+        fs::create_dir_all(&temp_dir).unwrap();
+
+        StreamConfig {
+            memory_limit,
+            temp_dir,
+            merge_fan_in: 3,
+        }
+    }
+
+    #[test]
+    fn same_answers_as_in_memory() {
+        let input = generate::generate(1, 1000);
+        let expected = parse(&input).unwrap();
+        let config = config("spill", 64);
+
+        let comparison = compare(input.as_bytes(), &config).unwrap();
+
+        assert_eq!(comparison.pairs, 1000);
+        assert_eq!(Answer::from(comparison.distance), part1(&expected));
+        assert_eq!(Answer::from(comparison.similarity), part2(&expected));
+        assert_eq!(fs::read_dir(&config.temp_dir).unwrap().count(), 0);
+        fs::remove_dir(&config.temp_dir).unwrap();
+
+        let comparison = compare(
+            include_str!("../input_sample").as_bytes(),
+            &StreamConfig::default(),
+        )
+        .unwrap();
+        assert_eq!((comparison.distance, comparison.similarity), (11, 31));
+    }
+
+    #[test]
+    fn merges_down_to_fan_in() {
+        let config = config("fan-in", 64);
+        let mut list = ExternalSort::new(&config, 4, 16);
+        for number in (0..100).rev() {
+            list.push(number).unwrap();
+        }
+
+        // 25 runs of 4 numbers, merged into 9 and then into 3
+        let list = list.finish().unwrap();
+        assert_eq!(list.spilled.len(), 3);
+        assert_eq!(
+            list.iter()
+                .unwrap()
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap(),
+            (0..100).collect::<Vec<_>>()
+        );
+
+        drop(list);
+        assert_eq!(fs::read_dir(&config.temp_dir).unwrap().count(), 0);
+        fs::remove_dir(&config.temp_dir).unwrap();
+    }
+
+    #[test]
+    fn stream_errors() {
+        let config = config("errors", 8);
+
+        assert!(matches!(
+            compare("1   2\n3   4\n5\n6\n".as_bytes(), &config),
+            Err(StreamError::MismatchedLengths { left: 4, right: 2 })
+        ));
+        assert!(matches!(
+            compare("1   2\n    4\n".as_bytes(), &config),
+            Err(StreamError::MismatchedLengths { left: 1, right: 2 })
+        ));
+        assert!(matches!(
+            compare("1   2\n3   x4\n".as_bytes(), &config),
+            Err(StreamError::Parse(ParseError::ExpectedNumber { location, .. }))
+                if location == Location::new(2, 5)
+        ));
+        assert_eq!(fs::read_dir(&config.temp_dir).unwrap().count(), 0);
+        fs::remove_dir(&config.temp_dir).unwrap();
+    }
+
+    #[test]
+    fn overflow() {
+        let config = config("overflow", 1 << 20);
+
+        // 4000000000 times 70000 squared is more than a u64 holds
+        let input = "4000000000   4000000000\n".repeat(70_000);
+        assert!(matches!(
+            compare(input.as_bytes(), &config),
+            Err(StreamError::Overflow {
+                total: "similarity"
+            })
+        ));
+        fs::remove_dir(&config.temp_dir).unwrap();
+    }
+}
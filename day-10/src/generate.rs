@@ -0,0 +1,34 @@
+use common::Rng;
+use grid::Grid;
+
+pub const DEFAULT_SIZE: usize = 45;
+
+/// A `size` by `size` topographic map of hills, one per 40 positions. The
+/// height drops by one for every step away from the nearest top, so trails
+/// lead up from every 0 in sight, and a few heights are random.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    let grid = Grid::new(size, size, ());
+    let points = grid.points().collect::<Vec<_>>();
+    let tops = (0..(size * size / 40).max(1))
+        .map(|_| *rng.choose(&points))
+        .collect::<Vec<_>>();
+
+    let map = grid.render(|point, _| {
+        let height = if rng.chance(0.02) {
+            rng.between(0, 9) as usize
+        } else {
+            let distance = tops
+                .iter()
+                .map(|top| top.manhattan_distance(point))
+                .min()
+                .unwrap();
+            9usize.saturating_sub(distance)
+        };
+        char::from(b'0' + height as u8)
+    });
+
+    format!("{map}\n")
+}
@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+
+use common::Answer;
+pub use grid::ParseError;
+use grid::{Grid, Image, Point, Rgb};
+
+pub mod generate;
+
+// The positions of height 9 that can be reached from `start`
+fn reachable_tops(grid: &Grid<u32>, start: Point) -> Vec<Point> {
+    let mut reachable = Grid::new(grid.width(), grid.height(), false);
+    let mut tops = Vec::new();
+
+    let mut queue = vec![start];
+    reachable[start] = true;
+    while let Some(position) = queue.pop() {
+        if grid[position] == 9 {
+            tops.push(position);
+        }
+        for neighbour in grid.neighbours4(position) {
+            if reachable[neighbour] {
+                continue;
+            }
+            if grid[neighbour] == grid[position] + 1 {
+                reachable[neighbour] = true;
+                queue.push(neighbour);
+            }
+        }
+    }
+
+    tops
+}
+
+fn bfs2(grid: &Grid<u32>, start: Point) -> usize {
+    let mut ways_reachable = Grid::new(grid.width(), grid.height(), 0);
+    ways_reachable[start] = 1;
+
+    let mut queue = VecDeque::from([start]);
+    while let Some(position) = queue.pop_front() {
+        for neighbour in grid.neighbours4(position) {
+            if grid[neighbour] == grid[position] + 1 {
+                ways_reachable[neighbour] += ways_reachable[position];
+                if !queue.contains(&neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+    }
+
+    grid.positions(|height| *height == 9)
+        .map(|position| ways_reachable[position])
+        .sum()
+}
+
+pub type Input = Grid<u32>;
+
+// Impassable tiles are given a height that no trail can reach
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    Grid::try_parse(input, "a digit or '.'", |c| match c {
+        '.' => Some(11),
+        c => c.to_digit(10),
+    })
+}
+
+fn trailheads(grid: &Grid<u32>) -> impl Iterator<Item = Point> + '_ {
+    grid.positions(|height| *height == 0)
+}
+
+pub fn part1(grid: &Input) -> Answer {
+    trailheads(grid)
+        .map(|start| reachable_tops(grid, start).len())
+        .sum::<usize>()
+        .into()
+}
+
+pub fn part2(grid: &Input) -> Answer {
+    trailheads(grid)
+        .map(|start| bfs2(grid, start))
+        .sum::<usize>()
+        .into()
+}
+
+/// The height map from dark at 0 to light at 9, with trailheads that lead
+/// to a top in green and the tops they lead to in red
+pub fn image(grid: &Input) -> Image {
+    let mut image = Image::new(grid, |_, height| match height {
+        0..=9 => Rgb(20, 40, 20).blend(Rgb(230, 255, 230), *height as f64 / 9.0),
+        _ => Rgb::BLACK,
+    });
+
+    for start in trailheads(grid) {
+        let tops = reachable_tops(grid, start);
+        if !tops.is_empty() {
+            image.highlight([start], Rgb::GREEN);
+            image.highlight(tops, Rgb::RED);
+        }
+    }
+
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+
+        assert_eq!(part1(&parse(input).unwrap()), Answer::Number(1));
+    }
+
+    #[test]
+    fn sample_input_2() {
+        let input = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(36));
+        assert_eq!(part2(&input), Answer::Number(81));
+    }
+
+    #[test]
+    fn sample_input_3() {
+        let input = ".....0.
+..4321.
+..5..2.
+..6543.
+..7..4.
+..8765.
+..9....";
+
+        assert_eq!(part2(&parse(input).unwrap()), Answer::Number(3));
+    }
+}
@@ -0,0 +1,20 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 8;
+
+/// `size` stones, a mix of zeroes, small numbers and numbers of up to
+/// seven digits
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let stones = (0..size)
+        .map(|_| match rng.below(4) {
+            0 => 0,
+            1 => rng.between(1, 99),
+            _ => rng.between(100, 9_999_999),
+        })
+        .map(|stone| stone.to_string())
+        .collect::<Vec<_>>();
+
+    format!("{}\n", stones.join(" "))
+}
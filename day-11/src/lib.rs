@@ -0,0 +1,111 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    ExpectedNumber { location: Location, found: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::ExpectedNumber { found, .. } => {
+                write!(f, "expected a number, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::ExpectedNumber { location, .. } => *location,
+        }
+    }
+}
+
+fn amount_of_stones(
+    stone_number: u64,
+    steps: usize,
+    cache: &mut HashMap<(u64, usize), u64>,
+) -> u64 {
+    if let Some(result) = cache.get(&(stone_number, steps)) {
+        return *result;
+    }
+
+    let num_stones = if steps == 0 {
+        1
+    } else {
+        if stone_number == 0 {
+            amount_of_stones(1, steps - 1, cache)
+        } else {
+            let n_str = stone_number.to_string();
+            if n_str.len().is_multiple_of(2) {
+                amount_of_stones(
+                    n_str[0..n_str.len() / 2].parse::<u64>().unwrap(),
+                    steps - 1,
+                    cache,
+                ) + amount_of_stones(
+                    n_str[n_str.len() / 2..].parse::<u64>().unwrap(),
+                    steps - 1,
+                    cache,
+                )
+            } else {
+                amount_of_stones(stone_number * 2024, steps - 1, cache)
+            }
+        }
+    };
+
+    cache.insert((stone_number, steps), num_stones);
+
+    num_stones
+}
+
+pub type Input = Vec<u64>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    input
+        .split_whitespace()
+        .map(|n| {
+            n.parse::<u64>().map_err(|_| ParseError::ExpectedNumber {
+                location: Location::of(input, n),
+                found: String::from(n),
+            })
+        })
+        .collect()
+}
+
+fn total_stones(stones: &[u64], steps: usize) -> u64 {
+    let mut cache = HashMap::<(u64, usize), u64>::new();
+    stones
+        .iter()
+        .map(|stone| amount_of_stones(*stone, steps, &mut cache))
+        .sum::<u64>()
+}
+
+pub fn part1(stones: &Input) -> Answer {
+    total_stones(stones, 25).into()
+}
+
+pub fn part2(stones: &Input) -> Answer {
+    total_stones(stones, 75).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(55312));
+        assert_eq!(part2(&input), Answer::Number(65601038650482));
+    }
+}
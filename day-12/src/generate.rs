@@ -0,0 +1,33 @@
+use common::Rng;
+use grid::{Grid, Point};
+
+pub const DEFAULT_SIZE: usize = 140;
+
+/// A `size` by `size` garden of irregular regions, grown at random from
+/// about one seed plant per 60 plots
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    let mut grid = Grid::new(size, size, None);
+    let points = grid.points().collect::<Vec<_>>();
+    let mut frontier = (0..(size * size / 60).max(1))
+        .map(|_| (*rng.choose(&points), char::from(b'A' + rng.below(26) as u8)))
+        .collect::<Vec<(Point, char)>>();
+
+    while !frontier.is_empty() {
+        let (position, plant) = frontier.swap_remove(rng.index(frontier.len()));
+        if grid[position].is_some() {
+            continue;
+        }
+
+        grid[position] = Some(plant);
+        frontier.extend(
+            grid.neighbours4(position)
+                .filter(|neighbour| grid[*neighbour].is_none())
+                .map(|neighbour| (neighbour, plant)),
+        );
+    }
+
+    format!("{}\n", grid.render(|_, plant| plant.unwrap()))
+}
@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+
+use common::Answer;
+pub use grid::ParseError;
+use grid::{Direction, Grid, Image, Point, Rgb};
+
+pub mod generate;
+
+fn bfs(grid: &Grid<char>, visited: &mut Grid<bool>, start: Point) -> (u32, u32, u32) {
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+
+    let mut area = 1;
+    let mut perimeter = 0;
+
+    let mut visited_this_round = Grid::new(grid.width(), grid.height(), false);
+    visited_this_round[start] = true;
+
+    while let Some(position) = queue.pop_front() {
+        for neighbour in position.neighbours4() {
+            if grid.get(neighbour) == Some(&grid[position]) {
+                if !visited_this_round[neighbour] {
+                    visited_this_round[neighbour] = true;
+                    queue.push_back(neighbour);
+                    area += 1;
+                }
+            } else {
+                perimeter += 1;
+            }
+        }
+    }
+
+    let area = area;
+    let perimeter = perimeter;
+    let mut perimeter_2 = 0;
+
+    let in_region = |position: Point| visited_this_round.get(position) == Some(&true);
+
+    queue.push_back(start);
+    visited[start] = true;
+    while let Some(position) = queue.pop_front() {
+        for direction in Direction::ALL {
+            let neighbour = position.step(direction);
+            if in_region(neighbour) {
+                if !visited[neighbour] {
+                    visited[neighbour] = true;
+                    queue.push_back(neighbour);
+                }
+                continue;
+            }
+
+            // Only count a fence when the fence piece before it along the
+            // same side, to the left or above, doesn't exist
+            let previous = if direction.is_horizontal() {
+                Direction::Up.offset()
+            } else {
+                Direction::Left.offset()
+            };
+            if !in_region(position + previous) || in_region(neighbour + previous) {
+                perimeter_2 += 1;
+            }
+        }
+    }
+
+    (area, perimeter, perimeter_2)
+}
+
+pub type Input = Grid<char>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    Grid::parse(input, |c| c)
+}
+
+fn regions(grid: &Grid<char>) -> Vec<(u32, u32, u32)> {
+    let mut visited = Grid::new(grid.width(), grid.height(), false);
+    let mut regions = Vec::new();
+
+    for position in grid.points() {
+        if !visited[position] {
+            regions.push(bfs(grid, &mut visited, position));
+        }
+    }
+
+    regions
+}
+
+pub fn part1(grid: &Input) -> Answer {
+    regions(grid)
+        .into_iter()
+        .map(|(area, perimeter, _)| area * perimeter)
+        .sum::<u32>()
+        .into()
+}
+
+pub fn part2(grid: &Input) -> Answer {
+    regions(grid)
+        .into_iter()
+        .map(|(area, _, perimeter_2)| area * perimeter_2)
+        .sum::<u32>()
+        .into()
+}
+
+/// Every plant in its own colour, with the plots along a fence darker so
+/// neighbouring regions of similar colours stay apart
+pub fn image(grid: &Input) -> Image {
+    Image::new(grid, |position, plant| {
+        let colour = Rgb::from_key(*plant as u64);
+        if position
+            .neighbours4()
+            .all(|neighbour| grid.get(neighbour) == Some(plant))
+        {
+            colour
+        } else {
+            colour.blend(Rgb::BLACK, 0.35)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(140));
+        assert_eq!(part2(&input), Answer::Number(80));
+    }
+
+    #[test]
+    fn sample_input_2() {
+        let input = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(1930));
+        assert_eq!(part2(&input), Answer::Number(1206));
+    }
+
+    #[test]
+    fn sample_input_3() {
+        let input = "AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA";
+
+        assert_eq!(part2(&parse(input).unwrap()), Answer::Number(368));
+    }
+}
@@ -0,0 +1,32 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 320;
+
+/// `size` claw machines. About half of the prizes can be reached with at
+/// most 100 presses of each button, the others are at random positions.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let machines = (0..size)
+        .map(|_| {
+            let a = (rng.between(10, 99), rng.between(10, 99));
+            let b = (rng.between(10, 99), rng.between(10, 99));
+            let prize = if rng.chance(0.5) {
+                let (a_presses, b_presses) = (rng.between(1, 100), rng.between(1, 100));
+                (
+                    a.0 * a_presses + b.0 * b_presses,
+                    a.1 * a_presses + b.1 * b_presses,
+                )
+            } else {
+                (rng.between(1000, 20000), rng.between(1000, 20000))
+            };
+
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            )
+        })
+        .collect::<Vec<_>>();
+
+    machines.join("\n")
+}
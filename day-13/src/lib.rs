@@ -0,0 +1,229 @@
+use core::f64;
+use std::{error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    ExpectedText {
+        location: Location,
+        expected: &'static str,
+        found: String,
+    },
+    ExpectedNumber {
+        location: Location,
+        found: String,
+    },
+    MissingLine {
+        location: Location,
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::ExpectedText {
+                expected, found, ..
+            } => write!(f, "expected {expected:?}, found {found:?}"),
+            ParseError::ExpectedNumber { found, .. } => {
+                write!(f, "expected a number, found {found:?}")
+            }
+            ParseError::MissingLine { expected, .. } => {
+                write!(f, "expected a line starting with {expected:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::ExpectedText { location, .. }
+            | ParseError::ExpectedNumber { location, .. }
+            | ParseError::MissingLine { location, .. } => *location,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    a: (u64, u64),
+    b: (u64, u64),
+    prize: (u64, u64),
+}
+
+impl Machine {
+    fn solve(&self) -> Option<u64> {
+        // aa*x = y
+        // ab*x + bb = y
+        let aa = self.a.1 as f64 / self.a.0 as f64;
+        let ab = self.b.1 as f64 / self.b.0 as f64;
+        let ap = self.prize.1 as f64 / self.prize.0 as f64;
+        let bb = self.prize.1 as f64 - ab * self.prize.0 as f64;
+        if (aa - ab).abs() <= f64::EPSILON {
+            return if (aa - ap).abs() <= f64::EPSILON {
+                self.solve_collinear()
+            } else {
+                None
+            };
+        }
+
+        let x_intersection = bb / (aa - ab);
+        let a_presses = (x_intersection / self.a.0 as f64) as u64;
+        let b_presses = ((self.prize.0 as f64 - x_intersection) / self.b.0 as f64) as u64;
+
+        for a in a_presses.saturating_sub(2)..(a_presses + 2) {
+            for b in b_presses.saturating_sub(2)..(b_presses + 2) {
+                if self.a.0 * a + self.b.0 * b == self.prize.0
+                    && self.a.1 * a + self.b.1 * b == self.prize.1
+                {
+                    return Some(a * 3 + b);
+                }
+            }
+        }
+
+        None
+    }
+
+    // Both buttons move the claw along the same line as the prize, so there can be many
+    // combinations. Press the button that is cheapest per unit of distance as often as possible
+    // and fill the remainder with the other one.
+    fn solve_collinear(&self) -> Option<u64> {
+        let (cheap, cheap_cost, other, other_cost) = if self.a.0 <= 3 * self.b.0 {
+            (self.b, 1, self.a, 3)
+        } else {
+            (self.a, 3, self.b, 1)
+        };
+
+        let max_cheap = self.prize.0 / cheap.0;
+        (0..=max_cheap.min(other.0))
+            .map(|i| max_cheap - i)
+            .find_map(|cheap_presses| {
+                let rest = self.prize.0 - cheap_presses * cheap.0;
+                let other_presses = rest / other.0;
+                (rest.is_multiple_of(other.0)
+                    && cheap.1 * cheap_presses + other.1 * other_presses == self.prize.1)
+                    .then_some(cheap_presses * cheap_cost + other_presses * other_cost)
+            })
+    }
+}
+
+pub type Input = Vec<Machine>;
+
+// Parse a line like "Button A: X+94, Y+34" given "Button A: X+" and ", Y+"
+fn parse_line(
+    input: &str,
+    line: Option<&str>,
+    prefix: &'static str,
+    separator: &'static str,
+) -> Result<(u64, u64), ParseError> {
+    let line = line.ok_or_else(|| ParseError::MissingLine {
+        location: Location::end_of(input),
+        expected: prefix,
+    })?;
+    let expected = |expected, found: &str| ParseError::ExpectedText {
+        location: Location::of(input, found),
+        expected,
+        found: String::from(found),
+    };
+    let number = |number: &str| {
+        number
+            .parse::<u64>()
+            .map_err(|_| ParseError::ExpectedNumber {
+                location: Location::of(input, number),
+                found: String::from(number),
+            })
+    };
+
+    let rest = line
+        .strip_prefix(prefix)
+        .ok_or_else(|| expected(prefix, line))?;
+    let (x, y) = rest
+        .split_once(separator)
+        .ok_or_else(|| expected(separator, &rest[rest.len()..]))?;
+
+    Ok((number(x)?, number(y)?))
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    input
+        .split("\n\n")
+        .filter(|block| !block.trim().is_empty())
+        .map(|block| {
+            let mut lines = block.lines();
+            let a = parse_line(input, lines.next(), "Button A: X+", ", Y+")?;
+            let b = parse_line(input, lines.next(), "Button B: X+", ", Y+")?;
+            let prize = parse_line(input, lines.next(), "Prize: X=", ", Y=")?;
+
+            Ok(Machine { a, b, prize })
+        })
+        .collect()
+}
+
+pub fn part1(machines: &Input) -> Answer {
+    machines
+        .iter()
+        .filter_map(|m| m.solve())
+        .sum::<u64>()
+        .into()
+}
+
+pub fn part2(machines: &Input) -> Answer {
+    machines
+        .iter()
+        .map(|m| Machine {
+            a: m.a,
+            b: m.b,
+            prize: (m.prize.0 + 10000000000000, m.prize.1 + 10000000000000),
+        })
+        .filter_map(|m| m.solve())
+        .sum::<u64>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(480));
+        assert_eq!(part2(&input), Answer::Number(875318608908));
+    }
+
+    #[test]
+    fn custom() {
+        let input = "Button A: X+1, Y+1
+Button B: X+2, Y+2
+Prize: X=11, Y=11";
+
+        assert_eq!(part1(&parse(input).unwrap()), Answer::Number(8));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse("Button A: X+1, Y+1\nButton B: X+2, Y=2").err(),
+            Some(ParseError::ExpectedText {
+                location: Location::new(2, 19),
+                expected: ", Y+",
+                found: String::new(),
+            })
+        );
+        assert_eq!(
+            parse("Button A: X+1, Y+1\nButton B: X+2, Y+2").err(),
+            Some(ParseError::MissingLine {
+                location: Location::new(2, 19),
+                expected: "Prize: X=",
+            })
+        );
+    }
+}
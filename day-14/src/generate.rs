@@ -0,0 +1,58 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 500;
+
+const WIDTH: i64 = 101;
+const HEIGHT: i64 = 103;
+
+/// `size` robots in the 101 by 103 space, of which a few hundred form a
+/// framed Christmas tree after a random number of seconds. The picture is
+/// always drawn, so there are at least that many robots.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let mut picture = Vec::new();
+    for x in 0..31 {
+        picture.extend([(x, 0), (x, 32)]);
+    }
+    for y in 1..32 {
+        picture.extend([(0, y), (30, y)]);
+    }
+    for row in 0..11 {
+        for x in 15 - row..=15 + row {
+            picture.push((x, 6 + row));
+        }
+    }
+    for y in 17..20 {
+        picture.extend([(14, y), (15, y), (16, y)]);
+    }
+
+    let seconds = rng.between(0, WIDTH * HEIGHT - 1);
+    let left = rng.between(0, WIDTH - 31);
+    let top = rng.between(0, HEIGHT - 33);
+
+    let mut robots = picture
+        .iter()
+        .map(|(x, y)| {
+            let velocity = (rng.between(-99, 99), rng.between(-99, 99));
+            // Walk back from the picture to where the robot starts
+            let position = (
+                (left + x - velocity.0 * seconds).rem_euclid(WIDTH),
+                (top + y - velocity.1 * seconds).rem_euclid(HEIGHT),
+            );
+            (position, velocity)
+        })
+        .collect::<Vec<_>>();
+    while robots.len() < size {
+        robots.push((
+            (rng.between(0, WIDTH - 1), rng.between(0, HEIGHT - 1)),
+            (rng.between(-99, 99), rng.between(-99, 99)),
+        ));
+    }
+    rng.shuffle(&mut robots);
+
+    robots
+        .iter()
+        .map(|((px, py), (vx, vy))| format!("p={px},{py} v={vx},{vy}\n"))
+        .collect()
+}
@@ -0,0 +1,226 @@
+use std::{cmp::max, error::Error, fmt, str::FromStr};
+
+use common::{Answer, InputError, Location};
+use grid::{Grid, Image, Point, Rgb};
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    ExpectedText {
+        location: Location,
+        expected: &'static str,
+        found: String,
+    },
+    ExpectedNumber {
+        location: Location,
+        found: String,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::ExpectedText {
+                expected, found, ..
+            } => write!(f, "expected {expected:?}, found {found:?}"),
+            ParseError::ExpectedNumber { found, .. } => {
+                write!(f, "expected a number, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::ExpectedText { location, .. }
+            | ParseError::ExpectedNumber { location, .. } => *location,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Robot {
+    position: (usize, usize),
+    velocity: (isize, isize),
+}
+
+impl Robot {
+    fn coords_after(&self, steps: isize, (xsize, ysize): (isize, isize)) -> (isize, isize) {
+        let mut coords = (
+            (self.position.0 as isize + self.velocity.0 * steps) % xsize,
+            (self.position.1 as isize + self.velocity.1 * steps) % ysize,
+        );
+        if coords.0 < 0 {
+            coords.0 += xsize;
+        }
+        if coords.1 < 0 {
+            coords.1 += ysize;
+        }
+        coords
+    }
+}
+
+pub struct Input {
+    robots: Vec<Robot>,
+    grid_size: (isize, isize),
+}
+
+fn parse_pair<T: FromStr>(input: &str, pair: &str) -> Result<(T, T), ParseError> {
+    let (a, b) = pair
+        .split_once(',')
+        .ok_or_else(|| ParseError::ExpectedText {
+            location: Location::of(input, &pair[pair.len()..]),
+            expected: ",",
+            found: String::new(),
+        })?;
+    let number = |number: &str| {
+        number.parse::<T>().map_err(|_| ParseError::ExpectedNumber {
+            location: Location::of(input, number),
+            found: String::from(number),
+        })
+    };
+
+    Ok((number(a)?, number(b)?))
+}
+
+// Parse a line like "p=0,4 v=3,-3"
+fn parse_robot(input: &str, line: &str) -> Result<Robot, ParseError> {
+    let expected = |expected, found: &str| ParseError::ExpectedText {
+        location: Location::of(input, found),
+        expected,
+        found: String::from(found),
+    };
+
+    let rest = line
+        .strip_prefix("p=")
+        .ok_or_else(|| expected("p=", line))?;
+    let (position, velocity) = rest
+        .split_once(" v=")
+        .ok_or_else(|| expected(" v=", &rest[rest.len()..]))?;
+
+    Ok(Robot {
+        position: parse_pair(input, position)?,
+        velocity: parse_pair(input, velocity)?,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let robots = input
+        .lines()
+        .map(|line| parse_robot(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Input {
+        robots,
+        grid_size: (101, 103),
+    })
+}
+
+/// Parse the sample from the puzzle text, which uses a smaller room
+pub fn parse_sample(input: &str) -> Result<Input, ParseError> {
+    Ok(Input {
+        grid_size: (11, 7),
+        ..parse(input)?
+    })
+}
+
+pub fn part1(input: &Input) -> Answer {
+    let grid_size = input.grid_size;
+
+    let safety_factors = input
+        .robots
+        .iter()
+        .map(|robot| robot.coords_after(100, grid_size))
+        .fold((0, 0, 0, 0), |(mut a, mut b, mut c, mut d), (x, y)| {
+            let xmid = grid_size.0 / 2;
+            let ymid = grid_size.1 / 2;
+
+            if x < xmid && y < ymid {
+                a += 1;
+            } else if x > xmid && y < ymid {
+                b += 1;
+            } else if x < xmid && y > ymid {
+                c += 1;
+            } else if x > xmid && y > ymid {
+                d += 1;
+            }
+
+            (a, b, c, d)
+        });
+
+    let safety_factor: usize =
+        safety_factors.0 * safety_factors.1 * safety_factors.2 * safety_factors.3;
+
+    safety_factor.into()
+}
+
+// The first number of seconds after which the robots line up in a long
+// row, which is when they draw the Christmas tree
+fn tree_steps(input: &Input) -> isize {
+    let grid_size = input.grid_size;
+
+    let mut steps = 0;
+    loop {
+        let mut grid = vec![vec![false; grid_size.1 as usize]; grid_size.0 as usize];
+        input.robots.iter().for_each(|robot| {
+            let (y, x) = robot.coords_after(steps, grid_size);
+            grid[y as usize][x as usize] = true;
+        });
+        let ma = grid
+            .iter()
+            .map(|line| {
+                let (ma, _) = line.iter().fold((0i32, 0i32), |(ma, curr), n| {
+                    let curr = if *n { curr + 1 } else { 0 };
+                    let ma = max(ma, curr);
+                    (ma, curr)
+                });
+                ma
+            })
+            .max()
+            .unwrap();
+        if ma > 10 {
+            return steps;
+        }
+        steps += 1;
+    }
+}
+
+pub fn part2(input: &Input) -> Answer {
+    (tree_steps(input) as usize).into()
+}
+
+/// The robots at the moment they draw the Christmas tree
+pub fn image(input: &Input) -> Image {
+    let steps = tree_steps(input);
+    let (width, height) = input.grid_size;
+
+    let mut grid = Grid::new(width as usize, height as usize, false);
+    for robot in &input.robots {
+        let (x, y) = robot.coords_after(steps, input.grid_size);
+        grid[Point::new(x, y)] = true;
+    }
+
+    Image::new(
+        &grid,
+        |_, robot| if *robot { Rgb::GREEN } else { Rgb::BLACK },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+
+        // The sample has no christmas tree to search for
+        let input = parse_sample(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(12));
+    }
+}
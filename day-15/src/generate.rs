@@ -0,0 +1,37 @@
+use common::Rng;
+use grid::{Direction, Grid};
+
+pub const DEFAULT_SIZE: usize = 50;
+
+/// A walled `size` by `size` warehouse with scattered walls and boxes,
+/// followed by 8 moves of the robot per tile
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(3);
+
+    let mut grid = Grid::new(size, size, '#');
+    let inside = grid
+        .points()
+        .filter(|point| {
+            (1..size as isize - 1).contains(&point.x) && (1..size as isize - 1).contains(&point.y)
+        })
+        .collect::<Vec<_>>();
+    for point in &inside {
+        grid[*point] = match rng.below(20) {
+            0 => '#',
+            1..=5 => 'O',
+            _ => '.',
+        };
+    }
+    grid[*rng.choose(&inside)] = '@';
+
+    let moves = (0..size * size * 8)
+        .map(|_| rng.choose(&Direction::ALL).arrow())
+        .collect::<Vec<_>>();
+    let moves = moves
+        .chunks(1000)
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<_>>();
+
+    format!("{grid}\n\n{}\n", moves.join("\n"))
+}
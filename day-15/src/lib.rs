@@ -0,0 +1,292 @@
+use std::{error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+use grid::{Direction, Grid, Image, Point, Rgb};
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Grid(grid::ParseError),
+    MissingMoves { location: Location },
+    MissingRobot { location: Location },
+    ExpectedMove { location: Location, found: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Grid(err) => write!(f, "{err}"),
+            ParseError::MissingMoves { .. } => {
+                write!(f, "expected an empty line followed by the moves")
+            }
+            ParseError::MissingRobot { .. } => write!(f, "expected a robot '@' in the grid"),
+            ParseError::ExpectedMove { found, .. } => {
+                write!(f, "expected a move '^', '>', 'v' or '<', found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::Grid(err) => err.location(),
+            ParseError::MissingMoves { location }
+            | ParseError::MissingRobot { location }
+            | ParseError::ExpectedMove { location, .. } => *location,
+        }
+    }
+}
+
+impl From<grid::ParseError> for ParseError {
+    fn from(err: grid::ParseError) -> Self {
+        ParseError::Grid(err)
+    }
+}
+
+fn calculate_gps(grid: &Grid<char>) -> usize {
+    grid.positions(|c| ['O', '['].contains(c))
+        .map(|position| position.y as usize * 100 + position.x as usize)
+        .sum::<usize>()
+}
+
+fn simulate(grid: &mut Grid<char>, direction: Direction, position: Point) -> Point {
+    let offset = direction.offset();
+    let mut steps: isize = 1;
+
+    loop {
+        let space = grid[position + offset * steps];
+        if space == '#' {
+            break;
+        } else if space == '.' {
+            for a in (1..(steps + 1)).rev() {
+                grid[position + offset * a] = grid[position + offset * (a - 1)];
+            }
+            return position + offset;
+        }
+        steps += 1;
+    }
+
+    position
+}
+
+fn scan_boxes(
+    grid: &mut Grid<char>,
+    position: Point,
+    direction: Direction,
+    prev_value: Option<char>,
+) -> bool {
+    let next = position.step(direction);
+    let space = grid[next];
+
+    let possible = match space {
+        '#' => false,
+        '.' => true,
+        '[' => {
+            if direction.is_horizontal() {
+                scan_boxes(grid, next, direction, prev_value.map(|_| space))
+            } else {
+                scan_boxes(grid, next, direction, prev_value.map(|_| space))
+                    && scan_boxes(
+                        grid,
+                        next.step(Direction::Right),
+                        direction,
+                        prev_value.map(|_| ']'),
+                    )
+            }
+        }
+        ']' => {
+            if direction.is_horizontal() {
+                scan_boxes(grid, next, direction, prev_value.map(|_| space))
+            } else {
+                scan_boxes(grid, next, direction, prev_value.map(|_| space))
+                    && scan_boxes(
+                        grid,
+                        next.step(Direction::Left),
+                        direction,
+                        prev_value.map(|_| '['),
+                    )
+            }
+        }
+        _ => unreachable!(),
+    };
+
+    if let Some(prev) = prev_value {
+        if possible {
+            grid[position] = grid[next];
+            grid[next] = prev;
+        }
+    }
+
+    possible
+}
+
+fn simulate2(grid: &mut Grid<char>, direction: Direction, position: Point) -> Point {
+    if scan_boxes(grid, position, direction, None) {
+        scan_boxes(grid, position, direction, Some(grid[position]));
+        position.step(direction)
+    } else {
+        position
+    }
+}
+
+pub struct Input {
+    grid: Grid<char>,
+    moves: Vec<Direction>,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let (grid_input, moves_input) =
+        input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::MissingMoves {
+                location: Location::end_of(input),
+            })?;
+
+    let grid = Grid::try_parse(grid_input, "'#', '.', 'O' or '@'", |c| {
+        ['#', '.', 'O', '@'].contains(&c).then_some(c)
+    })?;
+    if grid.find(&'@').is_none() {
+        return Err(ParseError::MissingRobot {
+            location: Location::of(input, &grid_input[grid_input.len()..]),
+        });
+    }
+
+    let moves = moves_input
+        .char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(offset, c)| {
+            Direction::from_arrow(c).ok_or_else(|| ParseError::ExpectedMove {
+                location: Location::of(input, &moves_input[offset..]),
+                found: c,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Input { grid, moves })
+}
+
+// Follow all moves with `step`, returning the warehouse afterwards without
+// the robot and every position the robot was in
+fn run(
+    mut grid: Grid<char>,
+    moves: &[Direction],
+    step: fn(&mut Grid<char>, Direction, Point) -> Point,
+) -> (Grid<char>, Vec<Point>) {
+    let mut position = grid.find(&'@').unwrap();
+    grid[position] = '.';
+
+    let mut route = vec![position];
+    for direction in moves {
+        position = step(&mut grid, *direction, position);
+        route.push(position);
+    }
+
+    (grid, route)
+}
+
+fn widen(grid: &Grid<char>) -> Grid<char> {
+    let wide_input = (0..grid.height())
+        .map(|y| {
+            grid.row(y)
+                .iter()
+                .flat_map(|c| match c {
+                    '#' => ['#', '#'],
+                    '.' => ['.', '.'],
+                    'O' => ['[', ']'],
+                    '@' => ['@', '.'],
+                    _ => unreachable!(),
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Grid::parse(&wide_input, |c| c).expect("the widened rows are all the same length")
+}
+
+pub fn part1(input: &Input) -> Answer {
+    let (grid, _) = run(input.grid.clone(), &input.moves, simulate);
+
+    calculate_gps(&grid).into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    let (grid, _) = run(widen(&input.grid), &input.moves, simulate2);
+
+    calculate_gps(&grid).into()
+}
+
+/// The wide warehouse of part 2 after all moves, with the tiles the robot
+/// walked over in light blue and where it ended up in red
+pub fn image(input: &Input) -> Image {
+    let (grid, route) = run(widen(&input.grid), &input.moves, simulate2);
+
+    let mut image = Image::new(&grid, |_, c| match c {
+        '#' => Rgb::GREY,
+        '[' => Rgb(160, 110, 50),
+        ']' => Rgb(140, 95, 40),
+        _ => Rgb::WHITE,
+    });
+    image.highlight(
+        route
+            .iter()
+            .copied()
+            .filter(|position| grid[*position] == '.'),
+        Rgb(200, 220, 255),
+    );
+    image.highlight(route.last().copied(), Rgb::RED);
+
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(10092));
+        assert_eq!(part2(&input), Answer::Number(9021));
+    }
+
+    #[test]
+    fn sample_input_2() {
+        let input = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+        assert_eq!(part1(&parse(input).unwrap()), Answer::Number(2028));
+    }
+
+    #[test]
+    fn custom() {
+        let input = "########
+#......#
+#...O..#
+#.@O...#
+#......#
+#......#
+#......#
+########
+
+>>v>^^";
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(309));
+        assert_eq!(part2(&input), Answer::Number(315));
+    }
+}
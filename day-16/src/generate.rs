@@ -0,0 +1,66 @@
+use common::Rng;
+use grid::{Grid, Point};
+
+pub const DEFAULT_SIZE: usize = 141;
+
+/// A `size` by `size` maze, rounded up to an odd size. It starts out as a
+/// maze with exactly one path between any two tiles, after which some walls
+/// are knocked down so there are several best paths.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(5) | 1;
+
+    let mut grid = maze(&mut rng, size);
+    for _ in 0..size * size / 100 {
+        let wall = Point::new(
+            rng.between(1, size as i64 - 2) as isize,
+            rng.between(1, size as i64 - 2) as isize,
+        );
+        let horizontal =
+            grid[wall + Point::new(-1, 0)] == '.' && grid[wall + Point::new(1, 0)] == '.';
+        let vertical =
+            grid[wall + Point::new(0, -1)] == '.' && grid[wall + Point::new(0, 1)] == '.';
+        if horizontal != vertical {
+            grid[wall] = '.';
+        }
+    }
+
+    grid[Point::new(1, size as isize - 2)] = 'S';
+    grid[Point::new(size as isize - 2, 1)] = 'E';
+
+    format!("{grid}\n")
+}
+
+/// A maze on the odd tiles of a `size` by `size` grid carved by a randomised
+/// depth first search, so every open tile is reachable from every other one
+fn maze(rng: &mut Rng, size: usize) -> Grid<char> {
+    let mut grid = Grid::new(size, size, '#');
+    let start = Point::new(1, 1);
+    grid[start] = '.';
+
+    let mut stack = vec![start];
+    while let Some(&position) = stack.last() {
+        let unvisited = [
+            Point::new(2, 0),
+            Point::new(-2, 0),
+            Point::new(0, 2),
+            Point::new(0, -2),
+        ]
+        .into_iter()
+        .map(|step| position + step)
+        .filter(|next| grid.get(*next) == Some(&'#'))
+        .collect::<Vec<_>>();
+
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let next = *rng.choose(&unvisited);
+        grid[Point::new((position.x + next.x) / 2, (position.y + next.y) / 2)] = '.';
+        grid[next] = '.';
+        stack.push(next);
+    }
+
+    grid
+}
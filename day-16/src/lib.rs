@@ -0,0 +1,260 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashSet, VecDeque},
+    error::Error,
+    fmt,
+};
+
+use common::{Answer, InputError, Location};
+use grid::{Direction, Grid, Image, Point, Rgb};
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Grid(grid::ParseError),
+    MissingTile { location: Location, expected: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Grid(err) => write!(f, "{err}"),
+            ParseError::MissingTile { expected, .. } => {
+                write!(f, "expected a {expected:?} tile in the grid")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::Grid(err) => err.location(),
+            ParseError::MissingTile { location, .. } => *location,
+        }
+    }
+}
+
+impl From<grid::ParseError> for ParseError {
+    fn from(err: grid::ParseError) -> Self {
+        ParseError::Grid(err)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct State {
+    cost: usize,
+    position: Point,
+    direction: Direction,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct Input {
+    grid: Grid<char>,
+    start: Point,
+    target: Point,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let grid = Grid::try_parse(input, "'#', '.', 'S' or 'E'", |c| {
+        ['#', '.', 'S', 'E'].contains(&c).then_some(c)
+    })?;
+
+    let find = |tile| {
+        grid.find(&tile).ok_or(ParseError::MissingTile {
+            location: Location::end_of(input),
+            expected: tile,
+        })
+    };
+    let start = find('S')?;
+    let target = find('E')?;
+
+    Ok(Input {
+        grid,
+        start,
+        target,
+    })
+}
+
+// The lowest cost to reach every position, for each direction indexed in
+// the order of `Direction::ALL`
+fn dijkstra(grid: &Grid<char>, start: Point) -> Grid<[Option<usize>; 4]> {
+    let mut visited = grid.map(|_| [None; 4]);
+
+    let mut heap = BinaryHeap::new();
+    heap.push(State {
+        cost: 0,
+        position: start,
+        direction: Direction::Right,
+    });
+
+    while let Some(state) = heap.pop() {
+        if visited[state.position][state.direction as usize].is_some() {
+            continue;
+        }
+        if grid[state.position] == '#' {
+            continue;
+        }
+        visited[state.position][state.direction as usize] = Some(state.cost);
+
+        heap.push(State {
+            cost: state.cost + 1000,
+            position: state.position,
+            direction: state.direction.turn_left(),
+        });
+        heap.push(State {
+            cost: state.cost + 1000,
+            position: state.position,
+            direction: state.direction.turn_right(),
+        });
+        heap.push(State {
+            cost: state.cost + 1,
+            position: state.position.step(state.direction),
+            direction: state.direction,
+        });
+    }
+
+    visited
+}
+
+pub fn part1(input: &Input) -> Answer {
+    let visited = dijkstra(&input.grid, input.start);
+
+    let min_cost = visited[input.target].iter().min().unwrap().unwrap();
+
+    min_cost.into()
+}
+
+// Every tile that is part of at least one of the best paths
+fn best_path_tiles(input: &Input, visited: &Grid<[Option<usize>; 4]>) -> HashSet<Point> {
+    let target = input.target;
+    let min_cost = visited[target].iter().min().unwrap().unwrap();
+
+    let mut queue = VecDeque::<State>::new();
+    let mut in_best_path = HashSet::<Point>::new();
+    for (direction, final_cost) in Direction::ALL.into_iter().zip(visited[target]) {
+        if final_cost.unwrap() == min_cost {
+            queue.push_front(State {
+                cost: min_cost,
+                position: target,
+                direction,
+            })
+        }
+    }
+
+    while let Some(state) = queue.pop_back() {
+        in_best_path.insert(state.position);
+
+        if visited[state.position][state.direction.turn_left() as usize]
+            .map(|cost| cost == state.cost.wrapping_sub(1000))
+            .unwrap_or(false)
+        {
+            queue.push_front(State {
+                cost: state.cost - 1000,
+                position: state.position,
+                direction: state.direction.turn_left(),
+            });
+        }
+
+        if visited[state.position][state.direction.turn_right() as usize]
+            .map(|cost| cost == state.cost.wrapping_sub(1000))
+            .unwrap_or(false)
+        {
+            queue.push_front(State {
+                cost: state.cost - 1000,
+                position: state.position,
+                direction: state.direction.turn_right(),
+            });
+        }
+
+        let backward_pos = state.position.step(state.direction.reverse());
+        if visited[backward_pos][state.direction as usize]
+            .map(|cost| cost == state.cost.wrapping_sub(1))
+            .unwrap_or(false)
+        {
+            queue.push_front(State {
+                cost: state.cost - 1,
+                position: backward_pos,
+                direction: state.direction,
+            });
+        }
+    }
+
+    in_best_path
+}
+
+pub fn part2(input: &Input) -> Answer {
+    let visited = dijkstra(&input.grid, input.start);
+
+    best_path_tiles(input, &visited).len().into()
+}
+
+/// The maze with the tiles that were explored looking for the best paths in
+/// light blue and the tiles on a best path in yellow
+pub fn image(input: &Input) -> Image {
+    let visited = dijkstra(&input.grid, input.start);
+
+    let mut image = Image::new(&input.grid, |position, c| match c {
+        '#' => Rgb::GREY,
+        _ if visited[position].iter().any(Option::is_some) => Rgb(200, 220, 255),
+        _ => Rgb::WHITE,
+    });
+    image.highlight(best_path_tiles(input, &visited), Rgb::YELLOW);
+    image.highlight([input.start], Rgb::GREEN);
+    image.highlight([input.target], Rgb::RED);
+
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(7036));
+        assert_eq!(part2(&input), Answer::Number(45));
+    }
+
+    #[test]
+    fn sample_input_2() {
+        let input = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(11048));
+        assert_eq!(part2(&input), Answer::Number(64));
+    }
+}
@@ -0,0 +1,37 @@
+use common::Rng;
+
+use crate::{find_a_register, parse};
+
+pub const DEFAULT_SIZE: usize = 9;
+
+/// A program with the shape of the real puzzle inputs: take the lowest three
+/// bits of A, scramble them with a few XORs and bits from further up A, print
+/// them and shift A. Register A starts out with `size` three bit digits, so
+/// that is how many values part 1 prints. Programs that cannot print
+/// themselves are thrown away.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.clamp(1, 21) as u32;
+
+    loop {
+        let mut scramble = vec![vec![1, rng.below(8)], vec![4, rng.below(8)], vec![5, 5]];
+        rng.shuffle(&mut scramble[..2]);
+        scramble.insert(rng.index(4), vec![0, 3]);
+
+        let mut program = vec![2, 4, 1, rng.below(8), 7, 5];
+        program.extend(scramble.concat());
+        program.extend([3, 0]);
+
+        let a = 8u64.pow(size - 1) + rng.below(8u64.pow(size) - 8u64.pow(size - 1));
+        let program = program.iter().map(u64::to_string).collect::<Vec<_>>();
+        let text = format!(
+            "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            program.join(",")
+        );
+
+        let computer = parse(&text).expect("generated programs are valid");
+        if find_a_register(&computer, 0, 0).is_some() {
+            return text;
+        }
+    }
+}
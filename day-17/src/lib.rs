@@ -0,0 +1,439 @@
+use std::{error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    MissingLine {
+        location: Location,
+        expected: &'static str,
+    },
+    ExpectedText {
+        location: Location,
+        expected: &'static str,
+        found: String,
+    },
+    ExpectedNumber {
+        location: Location,
+        found: String,
+    },
+    ExpectedThreeBitNumber {
+        location: Location,
+        found: String,
+    },
+    // The program ends with an instruction without an operand
+    MissingOperand {
+        location: Location,
+    },
+    // Combo operand 7 doesn't stand for a value
+    ReservedOperand {
+        location: Location,
+        opcode: u8,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingLine { expected, .. } => {
+                write!(f, "expected a line starting with {expected:?}")
+            }
+            ParseError::ExpectedText {
+                expected, found, ..
+            } => write!(f, "expected {expected:?}, found {found:?}"),
+            ParseError::ExpectedNumber { found, .. } => {
+                write!(f, "expected a number, found {found:?}")
+            }
+            ParseError::ExpectedThreeBitNumber { found, .. } => {
+                write!(f, "expected a number from 0 to 7, found {found:?}")
+            }
+            ParseError::MissingOperand { .. } => {
+                write!(f, "expected an operand after the last instruction")
+            }
+            ParseError::ReservedOperand { opcode, .. } => {
+                write!(
+                    f,
+                    "instruction {opcode} takes a combo operand, which can't be 7"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::MissingLine { location, .. }
+            | ParseError::ExpectedText { location, .. }
+            | ParseError::ExpectedNumber { location, .. }
+            | ParseError::ExpectedThreeBitNumber { location, .. }
+            | ParseError::MissingOperand { location }
+            | ParseError::ReservedOperand { location, .. } => *location,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[repr(usize)]
+enum Register {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    registers: [u64; 3],
+    instruction_pointer: usize,
+    output: Vec<u8>,
+    program: Vec<u8>,
+}
+
+impl Computer {
+    fn from_input(input: &str) -> Result<Computer, ParseError> {
+        let mut lines = input.lines().filter(|line| !line.is_empty());
+        let mut line_after = |prefix: &'static str| {
+            let line = lines.next().ok_or(ParseError::MissingLine {
+                location: Location::end_of(input),
+                expected: prefix,
+            })?;
+            line.strip_prefix(prefix)
+                .ok_or_else(|| ParseError::ExpectedText {
+                    location: Location::of(input, line),
+                    expected: prefix,
+                    found: String::from(line),
+                })
+        };
+
+        let mut registers = [0; 3];
+        for (register, prefix) in
+            registers
+                .iter_mut()
+                .zip(["Register A: ", "Register B: ", "Register C: "])
+        {
+            let value = line_after(prefix)?;
+            *register = value.parse().map_err(|_| ParseError::ExpectedNumber {
+                location: Location::of(input, value),
+                found: String::from(value),
+            })?;
+        }
+
+        let program_line = line_after("Program: ")?;
+        let numbers = program_line.split(',').collect::<Vec<_>>();
+        let program = numbers
+            .iter()
+            .map(|s| match s.parse() {
+                Ok(instruction @ 0..=7) => Ok(instruction),
+                _ => Err(ParseError::ExpectedThreeBitNumber {
+                    location: Location::of(input, s),
+                    found: String::from(*s),
+                }),
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        if program.len() % 2 == 1 {
+            return Err(ParseError::MissingOperand {
+                location: Location::of(input, &program_line[program_line.len()..]),
+            });
+        }
+        // adv, bst, out, bdv and cdv take a combo operand
+        for (i, instruction) in program.chunks(2).enumerate() {
+            if matches!(instruction, [0 | 2 | 5 | 6 | 7, 7]) {
+                return Err(ParseError::ReservedOperand {
+                    location: Location::of(input, numbers[2 * i + 1]),
+                    opcode: instruction[0],
+                });
+            }
+        }
+
+        Ok(Computer {
+            registers,
+            instruction_pointer: 0,
+            output: Vec::new(),
+            program,
+        })
+    }
+
+    fn combo_operand(&self, operand: u8) -> u64 {
+        match operand {
+            0..=3 => operand as u64,
+            4..=6 => self.registers[(operand - 4) as usize],
+            _ => unreachable!("combo operand 7 is rejected by the parser"),
+        }
+    }
+
+    fn dv(&mut self, register: Register, operand: u8) -> usize {
+        let numerator = self.registers[Register::A as usize];
+        // Shifting out every bit leaves 0, rather than overflowing
+        self.registers[register as usize] = u32::try_from(self.combo_operand(operand))
+            .ok()
+            .and_then(|shift| numerator.checked_shr(shift))
+            .unwrap_or(0);
+        self.instruction_pointer + 2
+    }
+
+    fn adv(&mut self, operand: u8) -> usize {
+        self.dv(Register::A, operand)
+    }
+
+    fn bdv(&mut self, operand: u8) -> usize {
+        self.dv(Register::B, operand)
+    }
+
+    fn cdv(&mut self, operand: u8) -> usize {
+        self.dv(Register::C, operand)
+    }
+
+    fn bxl(&mut self, operand: u8) -> usize {
+        self.registers[Register::B as usize] ^= operand as u64;
+        self.instruction_pointer + 2
+    }
+
+    fn bxc(&mut self, _operand: u8) -> usize {
+        self.registers[Register::B as usize] ^= self.registers[Register::C as usize];
+        self.instruction_pointer + 2
+    }
+
+    fn bst(&mut self, operand: u8) -> usize {
+        self.registers[Register::B as usize] = self.combo_operand(operand) % 8;
+        self.instruction_pointer + 2
+    }
+
+    fn jnz(&mut self, operand: u8) -> usize {
+        if self.registers[Register::A as usize] == 0 {
+            self.instruction_pointer + 2
+        } else {
+            operand as usize
+        }
+    }
+
+    fn out(&mut self, operand: u8) -> usize {
+        self.output.push((self.combo_operand(operand) % 8) as u8);
+        self.instruction_pointer + 2
+    }
+
+    fn execute_instruction(&mut self) {
+        let opcode = self.program[self.instruction_pointer];
+        let operand = self.program[self.instruction_pointer + 1];
+        self.instruction_pointer = match opcode {
+            0 => self.adv(operand),
+            1 => self.bxl(operand),
+            2 => self.bst(operand),
+            3 => self.jnz(operand),
+            4 => self.bxc(operand),
+            5 => self.out(operand),
+            6 => self.bdv(operand),
+            7 => self.cdv(operand),
+            _ => panic!("Invalid opcode"),
+        }
+    }
+
+    // A jump to an odd position can leave the last number as an opcode
+    // without an operand, which halts like jumping past the end
+    fn run(&mut self) {
+        while self.instruction_pointer + 1 < self.program.len() {
+            self.execute_instruction();
+        }
+    }
+
+    fn get_output(&self) -> String {
+        self.output
+            .iter()
+            .map(|o| o.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+pub type Input = Computer;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    Computer::from_input(input)
+}
+
+pub fn part1(computer: &Input) -> Answer {
+    let mut computer = computer.clone();
+
+    computer.run();
+
+    computer.get_output().into()
+}
+
+/*
+Program: 2,4,1,5,7,5,4,3,1,6,0,3,5,5,3,0
+
+2,4  bst 4  B=A%8
+1,5  bxl 5  B=B^5
+7,5  cdv 5  C=A>>B
+4,3  bxc 3  B=B^C
+1,6  bxl 6  B=B^6
+0,3  adv 3  A=A>>3
+5,5  out 5  output.push(B%8)
+3,0  jnz 0  if A==0 { Stop } else { Restart }
+
+Every iteration outputs one value and shifts A by 3 bits, so A can be built up 3 bits at a time
+by matching the program from its last value backwards.
+*/
+fn find_a_register(computer: &Computer, a: u64, matched: usize) -> Option<u64> {
+    if matched == computer.program.len() {
+        return Some(a);
+    }
+    for new_a_bits in 0..8 {
+        let tmp_a = (a << 3) | new_a_bits;
+
+        let mut tmp_computer = computer.clone();
+        tmp_computer.registers[Register::A as usize] = tmp_a;
+        tmp_computer.run();
+
+        if tmp_computer.output.len() == matched + 1
+            && computer.program.ends_with(&tmp_computer.output)
+        {
+            let t = find_a_register(computer, tmp_a, matched + 1);
+            if t.is_some() {
+                return t;
+            }
+        }
+    }
+    None
+}
+
+pub fn part2(computer: &Input) -> Answer {
+    find_a_register(computer, 0, 0).unwrap().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+
+        assert_eq!(
+            part1(&parse(input).unwrap()),
+            Answer::Text(String::from("4,6,3,5,6,3,5,2,1,0"))
+        );
+    }
+
+    #[test]
+    fn sample_input_2() {
+        let input = "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+        assert_eq!(part2(&parse(input).unwrap()), Answer::Number(117440));
+    }
+
+    #[test]
+    fn test_1() {
+        let input = "Register A: 0
+Register B: 0
+Register C: 9
+
+Program: 2,6";
+
+        let mut computer = Computer::from_input(input).unwrap();
+        computer.run();
+        assert!(computer.registers[Register::B as usize] == 1);
+    }
+
+    #[test]
+    fn test_2() {
+        let input = "Register A: 10
+Register B: 0
+Register C: 0
+
+Program: 5,0,5,1,5,4";
+
+        let mut computer = Computer::from_input(input).unwrap();
+        computer.run();
+        assert!(computer.get_output().as_str() == "0,1,2");
+    }
+
+    #[test]
+    fn test_3() {
+        let input = "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+        let mut computer = Computer::from_input(input).unwrap();
+        computer.run();
+        assert!(computer.get_output().as_str() == "4,2,5,6,7,7,7,7,3,1,0");
+        assert!(computer.registers[Register::A as usize] == 0);
+    }
+
+    #[test]
+    fn test_4() {
+        let input = "Register A: 0
+Register B: 29
+Register C: 0
+
+Program: 1,7";
+
+        let mut computer = Computer::from_input(input).unwrap();
+        computer.run();
+        assert!(computer.registers[Register::B as usize] == 26);
+    }
+
+    #[test]
+    fn test_5() {
+        let input = "Register A: 0
+Register B: 2024
+Register C: 43690
+
+Program: 4,0";
+
+        let mut computer = Computer::from_input(input).unwrap();
+        computer.run();
+        assert!(computer.registers[Register::B as usize] == 44354);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse("Register A: 1\nRegister B: 2\nRegister C: x\n\nProgram: 0,1").err(),
+            Some(ParseError::ExpectedNumber {
+                location: Location::new(3, 13),
+                found: String::from("x"),
+            })
+        );
+        assert_eq!(
+            parse("Register A: 1\nRegister B: 2\nRegister C: 3\n\nProgram: 0,8").err(),
+            Some(ParseError::ExpectedThreeBitNumber {
+                location: Location::new(5, 12),
+                found: String::from("8"),
+            })
+        );
+        assert_eq!(
+            parse("Register A: 1\nRegister B: 2\nRegister C: 3\n\nProgram: 5").err(),
+            Some(ParseError::MissingOperand {
+                location: Location::new(5, 11),
+            })
+        );
+        assert_eq!(
+            parse("Register A: 1\nRegister B: 2\nRegister C: 3\n\nProgram: 1,7,5,7").err(),
+            Some(ParseError::ReservedOperand {
+                location: Location::new(5, 16),
+                opcode: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn halts_instead_of_panicking() {
+        // A jump to the last number, and a shift by more than 64 bits
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,3,5,4";
+        assert_eq!(part1(&parse(input).unwrap()), Answer::from(""));
+
+        let input = "Register A: 1\nRegister B: 100\nRegister C: 0\n\nProgram: 0,5,5,4";
+        assert_eq!(part1(&parse(input).unwrap()), Answer::from("0"));
+    }
+}
@@ -0,0 +1,53 @@
+use common::Rng;
+use grid::{Grid, Point};
+
+use crate::bfs;
+
+pub const DEFAULT_SIZE: usize = 3450;
+
+const GRID_SIZE: usize = 71;
+const PART_1_LIMIT: usize = 1024;
+
+/// `size` falling bytes in the 71 by 71 memory space. The exit stays
+/// reachable after the first kilobyte and is always cut off at some point,
+/// even if that takes more than `size` bytes.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let end = Point::new(GRID_SIZE as isize - 1, GRID_SIZE as isize - 1);
+    let mut bytes = Grid::new(GRID_SIZE, GRID_SIZE, true)
+        .points()
+        .filter(|point| *point != Point::ZERO && *point != end)
+        .collect::<Vec<_>>();
+
+    loop {
+        rng.shuffle(&mut bytes);
+
+        let blocked_after = |fallen: usize| {
+            let mut grid = Grid::new(GRID_SIZE, GRID_SIZE, true);
+            for byte in &bytes[..fallen] {
+                grid[*byte] = false;
+            }
+            bfs(&grid).is_none()
+        };
+        if blocked_after(PART_1_LIMIT) {
+            continue;
+        }
+
+        // Binary search for the first byte that cuts off the exit
+        let (mut open, mut blocked) = (PART_1_LIMIT, bytes.len());
+        while blocked - open > 1 {
+            let middle = (open + blocked) / 2;
+            if blocked_after(middle) {
+                blocked = middle;
+            } else {
+                open = middle;
+            }
+        }
+
+        return bytes[..size.clamp(blocked, bytes.len())]
+            .iter()
+            .map(|byte| format!("{byte}\n"))
+            .collect();
+    }
+}
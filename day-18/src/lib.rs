@@ -0,0 +1,200 @@
+use std::{collections::VecDeque, error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+use grid::{Grid, Image, Point, Rgb};
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    MissingComma { location: Location },
+    ExpectedNumber { location: Location, found: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingComma { .. } => {
+                write!(f, "expected two coordinates separated by ','")
+            }
+            ParseError::ExpectedNumber { found, .. } => {
+                write!(f, "expected a number, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::MissingComma { location } | ParseError::ExpectedNumber { location, .. } => {
+                *location
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    cost: usize,
+    position: Point,
+}
+
+// The number of steps from the top left corner to every free position
+fn distances(grid: &Grid<bool>) -> Grid<Option<usize>> {
+    let mut queue = VecDeque::new();
+    let mut cost = grid.map(|_| None);
+    queue.push_back(State {
+        cost: 0,
+        position: Point::ZERO,
+    });
+    while let Some(current) = queue.pop_front() {
+        if cost[current.position].is_some() {
+            continue;
+        }
+        cost[current.position] = Some(current.cost);
+
+        for neighbour in grid.neighbours4(current.position) {
+            if grid[neighbour] {
+                queue.push_back(State {
+                    cost: current.cost + 1,
+                    position: neighbour,
+                });
+            }
+        }
+    }
+    cost
+}
+
+fn exit(grid: &Grid<bool>) -> Point {
+    Point::new(grid.width() as isize - 1, grid.height() as isize - 1)
+}
+
+fn bfs(grid: &Grid<bool>) -> Option<usize> {
+    distances(grid)[exit(grid)]
+}
+
+pub struct Input {
+    byte_locations: Vec<Point>,
+    grid_size: usize,
+    part_1_limit: usize,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let coordinate = |number: &str| {
+        number
+            .parse::<usize>()
+            .map(|n| n as isize)
+            .map_err(|_| ParseError::ExpectedNumber {
+                location: Location::of(input, number),
+                found: String::from(number),
+            })
+    };
+
+    let byte_locations = input
+        .lines()
+        .map(|line| {
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::MissingComma {
+                    location: Location::of(input, line),
+                })?;
+            Ok(Point::new(coordinate(x)?, coordinate(y)?))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Input {
+        byte_locations,
+        grid_size: 71,
+        part_1_limit: 1024,
+    })
+}
+
+/// Parse the sample from the puzzle text, which uses a smaller memory space
+/// and only simulates the first 12 bytes for part 1
+pub fn parse_sample(input: &str) -> Result<Input, ParseError> {
+    Ok(Input {
+        grid_size: 7,
+        part_1_limit: 12,
+        ..parse(input)?
+    })
+}
+
+pub fn part1(input: &Input) -> Answer {
+    let mut grid = Grid::new(input.grid_size, input.grid_size, true);
+    input
+        .byte_locations
+        .iter()
+        .take(input.part_1_limit)
+        .for_each(|position| {
+            grid[*position] = false;
+        });
+
+    bfs(&grid).unwrap().into()
+}
+
+// The first byte after which the exit can't be reached anymore
+fn first_blocked(input: &Input) -> Point {
+    let mut grid = Grid::new(input.grid_size, input.grid_size, true);
+    let first_blocked = input
+        .byte_locations
+        .iter()
+        .find(|position| {
+            grid[**position] = false;
+            bfs(&grid).is_none()
+        })
+        .unwrap();
+
+    *first_blocked
+}
+
+pub fn part2(input: &Input) -> Answer {
+    first_blocked(input).to_string().into()
+}
+
+/// The memory space after the bytes of part 1 have fallen with a shortest
+/// path to the exit, and in red the byte that cuts the exit off later
+pub fn image(input: &Input) -> Image {
+    let mut grid = Grid::new(input.grid_size, input.grid_size, true);
+    for position in input.byte_locations.iter().take(input.part_1_limit) {
+        grid[*position] = false;
+    }
+    let distances = distances(&grid);
+
+    let mut image = Image::new(&grid, |_, free| if *free { Rgb::WHITE } else { Rgb::GREY });
+
+    // Walk back from the exit to ever closer positions
+    let mut position = exit(&grid);
+    if let Some(mut distance) = distances[position] {
+        let mut path = vec![position];
+        while distance > 0 {
+            distance -= 1;
+            position = grid
+                .neighbours4(position)
+                .find(|neighbour| distances[*neighbour] == Some(distance))
+                .unwrap();
+            path.push(position);
+        }
+        image.path(path, Rgb::BLUE);
+    }
+    image.highlight([first_blocked(input)], Rgb::RED);
+
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+
+        let input = parse_sample(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(22));
+        assert_eq!(part2(&input), Answer::Text(String::from("6,1")));
+    }
+}
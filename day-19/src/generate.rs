@@ -0,0 +1,60 @@
+use std::collections::BTreeSet;
+
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 400;
+
+const COLOURS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+// How many towels there are of one to eight stripes, like in the real inputs
+const TOWELS_PER_LENGTH: [usize; 8] = [4, 23, 120, 100, 80, 60, 40, 20];
+
+/// 447 towel patterns and `size` designs of 40 to 60 stripes. One colour has
+/// no towel of its own and no towel starts with it twice, so the designs
+/// that start that way are impossible, which is found out quickly. All other
+/// designs are strung together from towels.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let missing = *rng.choose(&COLOURS);
+    let impossible_start = format!("{missing}{missing}");
+    let mut towels = COLOURS
+        .iter()
+        .filter(|colour| **colour != missing)
+        .map(|colour| colour.to_string())
+        .collect::<Vec<_>>();
+    for (length, count) in TOWELS_PER_LENGTH.iter().enumerate().skip(1) {
+        let mut of_length = BTreeSet::new();
+        while of_length.len() < *count {
+            let towel = stripes(&mut rng, length + 1);
+            if !towel.starts_with(&impossible_start) {
+                of_length.insert(towel);
+            }
+        }
+        towels.extend(of_length);
+    }
+    rng.shuffle(&mut towels);
+
+    let designs = (0..size)
+        .map(|_| {
+            let length = rng.between(40, 60) as usize;
+            let mut design = String::new();
+            if rng.chance(0.1) {
+                design.push_str(&impossible_start);
+                design.push_str(&stripes(&mut rng, length - 2));
+            } else {
+                while design.len() < length {
+                    let towel: &String = rng.choose(&towels);
+                    design.push_str(towel);
+                }
+            }
+            format!("{design}\n")
+        })
+        .collect::<String>();
+
+    format!("{}\n\n{designs}", towels.join(", "))
+}
+
+fn stripes(rng: &mut Rng, length: usize) -> String {
+    (0..length).map(|_| *rng.choose(&COLOURS)).collect()
+}
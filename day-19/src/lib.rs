@@ -0,0 +1,151 @@
+use std::{error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    MissingPatterns { location: Location },
+    EmptyTowel { location: Location },
+    ExpectedColour { location: Location, found: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingPatterns { .. } => {
+                write!(f, "expected an empty line followed by the designs")
+            }
+            ParseError::EmptyTowel { .. } => write!(f, "expected a towel with at least one stripe"),
+            ParseError::ExpectedColour { found, .. } => write!(
+                f,
+                "expected a stripe colour 'w', 'u', 'b', 'r' or 'g', found {found:?}"
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::MissingPatterns { location }
+            | ParseError::EmptyTowel { location }
+            | ParseError::ExpectedColour { location, .. } => *location,
+        }
+    }
+}
+
+fn is_possible(towels: &[&str], pattern: &str) -> bool {
+    if pattern.is_empty() {
+        return true;
+    }
+
+    for towel in towels.iter() {
+        if pattern.starts_with(*towel) && is_possible(towels, &pattern[towel.len()..]) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn ways_possible(towels: &[&str], pattern: &str, cache: &mut Vec<Option<usize>>) -> usize {
+    if let Some(cached_value) = cache[pattern.len()] {
+        cached_value
+    } else {
+        let sum = towels
+            .iter()
+            .map(|towel| {
+                if towel.len() > pattern.len() {
+                    0
+                } else if pattern.starts_with(*towel) {
+                    ways_possible(towels, &pattern[towel.len()..], cache)
+                } else {
+                    0
+                }
+            })
+            .sum();
+        cache[pattern.len()] = Some(sum);
+        sum
+    }
+}
+
+pub struct Input<'a> {
+    towels: Vec<&'a str>,
+    patterns: Vec<&'a str>,
+}
+
+fn check_stripes(input: &str, stripes: &str) -> Result<(), ParseError> {
+    match stripes
+        .char_indices()
+        .find(|(_, c)| !['w', 'u', 'b', 'r', 'g'].contains(c))
+    {
+        Some((offset, found)) => Err(ParseError::ExpectedColour {
+            location: Location::of(input, &stripes[offset..]),
+            found,
+        }),
+        None => Ok(()),
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    let (towels_input, pattern_input) =
+        input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::MissingPatterns {
+                location: Location::end_of(input),
+            })?;
+    let towels = towels_input.split(", ").collect::<Vec<_>>();
+    let patterns = pattern_input.lines().collect::<Vec<_>>();
+
+    // An empty towel would match any design forever
+    if let Some(towel) = towels.iter().find(|towel| towel.is_empty()) {
+        return Err(ParseError::EmptyTowel {
+            location: Location::of(input, towel),
+        });
+    }
+    for stripes in towels.iter().chain(patterns.iter()) {
+        check_stripes(input, stripes)?;
+    }
+
+    Ok(Input { towels, patterns })
+}
+
+pub fn part1(input: &Input) -> Answer {
+    input
+        .patterns
+        .iter()
+        .filter(|pattern| is_possible(&input.towels, pattern))
+        .count()
+        .into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    input
+        .patterns
+        .iter()
+        .map(|pattern| {
+            let mut cache = vec![None; pattern.len() + 1];
+            cache[0] = Some(1);
+            ways_possible(&input.towels, pattern, &mut cache)
+        })
+        .sum::<usize>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(6));
+        assert_eq!(part2(&input), Answer::Number(16));
+    }
+}
@@ -0,0 +1,30 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// `size` reports, most start out safe and some get one or more levels
+/// changed so they need the problem dampener or are unsafe altogether
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    (0..size)
+        .map(|_| {
+            let length = rng.between(5, 8) as usize;
+            let direction = if rng.chance(0.5) { 1 } else { -1 };
+            let mut level = rng.between(20, 80);
+            let mut report = Vec::with_capacity(length);
+            for _ in 0..length {
+                report.push(level);
+                level += direction * rng.between(1, 3);
+            }
+
+            while rng.chance(0.4) {
+                let i = rng.index(length);
+                report[i] = (report[i] + rng.between(-4, 4)).max(1);
+            }
+
+            let report = report.iter().map(i64::to_string).collect::<Vec<_>>();
+            format!("{}\n", report.join(" "))
+        })
+        .collect()
+}
@@ -0,0 +1,365 @@
+use std::{error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    ExpectedNumber { location: Location, found: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::ExpectedNumber { found, .. } => {
+                write!(f, "expected a number, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::ExpectedNumber { location, .. } => *location,
+        }
+    }
+}
+
+fn distances(report: &[u32]) -> Vec<i32> {
+    report
+        .windows(2)
+        .map(|pair| {
+            let (a, b) = (pair[0], pair[1]);
+            a as i32 - b as i32
+        })
+        .collect()
+}
+
+/// Why a report is or isn't safe. The index is that of the step, so step
+/// `i` goes from level `i` to level `i + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Safe,
+    DirectionChange { index: usize },
+    NoChange { index: usize },
+    StepTooLarge { index: usize, step: u32 },
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Safe => write!(f, "safe"),
+            Verdict::DirectionChange { index } => {
+                write!(f, "unsafe, changes direction at step {index}")
+            }
+            Verdict::NoChange { index } => write!(f, "unsafe, step {index} is 0"),
+            Verdict::StepTooLarge { index, step } => {
+                write!(f, "unsafe, step {index} of {step} is larger than 3")
+            }
+        }
+    }
+}
+
+/// The first reason `report` is unsafe. The direction of a report is set
+/// by its first step that isn't 0.
+pub fn diagnose(report: &[u32]) -> Verdict {
+    let distances = distances(report);
+    let direction = distances.iter().map(|d| d.signum()).find(|&d| d != 0);
+
+    for (index, &d) in distances.iter().enumerate() {
+        if d == 0 {
+            return Verdict::NoChange { index };
+        }
+        if Some(d.signum()) != direction {
+            return Verdict::DirectionChange { index };
+        }
+        if d.abs() > 3 {
+            return Verdict::StepTooLarge {
+                index,
+                step: d.unsigned_abs(),
+            };
+        }
+    }
+
+    Verdict::Safe
+}
+
+fn is_safe(report: &[u32]) -> bool {
+    diagnose(report) == Verdict::Safe
+}
+
+/// How many reports were found safe and how many failed for each reason
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub safe: usize,
+    pub direction_changes: usize,
+    pub no_changes: usize,
+    pub steps_too_large: usize,
+}
+
+impl Summary {
+    pub fn of(verdicts: &[Verdict]) -> Summary {
+        verdicts
+            .iter()
+            .fold(Summary::default(), |mut summary, verdict| {
+                match verdict {
+                    Verdict::Safe => summary.safe += 1,
+                    Verdict::DirectionChange { .. } => summary.direction_changes += 1,
+                    Verdict::NoChange { .. } => summary.no_changes += 1,
+                    Verdict::StepTooLarge { .. } => summary.steps_too_large += 1,
+                }
+                summary
+            })
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "safe: {}", self.safe)?;
+        writeln!(f, "direction change: {}", self.direction_changes)?;
+        writeln!(f, "step of 0: {}", self.no_changes)?;
+        write!(f, "step larger than 3: {}", self.steps_too_large)
+    }
+}
+
+/// Every report on a line of its own with its verdict, followed by the
+/// summary
+pub fn diagnostics(reports: &Input) -> String {
+    let verdicts = reports
+        .iter()
+        .map(|report| diagnose(report))
+        .collect::<Vec<_>>();
+
+    let mut listing = String::new();
+    for (i, (report, verdict)) in reports.iter().zip(&verdicts).enumerate() {
+        let levels = report.iter().map(u32::to_string).collect::<Vec<_>>();
+        listing.push_str(&format!("{}: {}: {verdict}\n", i + 1, levels.join(" ")));
+    }
+    listing.push_str(&format!("\n{}\n", Summary::of(&verdicts)));
+
+    listing
+}
+
+/// How forgiving the safety check is: how many levels may be removed from a
+/// report and how large every step between the remaining levels may be
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tolerance {
+    pub max_removed: usize,
+    pub min_step: u32,
+    pub max_step: u32,
+}
+
+impl Tolerance {
+    /// The Problem Dampener of part 2
+    pub const DAMPENER: Tolerance = Tolerance {
+        max_removed: 1,
+        min_step: 1,
+        max_step: 3,
+    };
+}
+
+/// The indices of the fewest levels to remove from `report` to make it
+/// safe, `None` if that takes more levels than the tolerance allows
+pub fn removed_levels(report: &[u32], tolerance: &Tolerance) -> Option<Vec<usize>> {
+    [true, false]
+        .into_iter()
+        .filter_map(|increasing| monotone_removals(report, tolerance, increasing))
+        .min_by_key(Vec::len)
+}
+
+// For every level the fewest removals before it when it is kept. Only the
+// `max_removed + 1` levels before it can be the previous kept level, so
+// this takes O(n * k) time for a report of n levels.
+fn monotone_removals(
+    report: &[u32],
+    tolerance: &Tolerance,
+    increasing: bool,
+) -> Option<Vec<usize>> {
+    let n = report.len();
+    let k = tolerance.max_removed;
+    if n <= 1 {
+        return Some(Vec::new());
+    }
+
+    let safe_step = |a: u32, b: u32| {
+        let step = if increasing {
+            b.checked_sub(a)
+        } else {
+            a.checked_sub(b)
+        };
+        step.is_some_and(|step| (tolerance.min_step..=tolerance.max_step).contains(&step))
+    };
+
+    let mut removals = vec![None; n];
+    let mut previous = vec![None; n];
+    for i in 0..n {
+        if i <= k {
+            removals[i] = Some(i);
+        }
+        for j in i.saturating_sub(k + 1)..i {
+            let Some(before) = removals[j] else {
+                continue;
+            };
+            let total = before + (i - j - 1);
+            if total <= k
+                && removals[i].is_none_or(|best| total < best)
+                && safe_step(report[j], report[i])
+            {
+                removals[i] = Some(total);
+                previous[i] = Some(j);
+            }
+        }
+    }
+
+    // Everything after the last kept level is removed as well
+    let last = (n.saturating_sub(k + 1)..n)
+        .filter_map(|i| Some((i, removals[i]? + (n - 1 - i))))
+        .filter(|&(_, total)| total <= k)
+        .min_by_key(|&(_, total)| total)?
+        .0;
+
+    let mut kept = vec![false; n];
+    let mut level = Some(last);
+    while let Some(i) = level {
+        kept[i] = true;
+        level = previous[i];
+    }
+
+    Some((0..n).filter(|&i| !kept[i]).collect())
+}
+
+pub type Input = Vec<Vec<u32>>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|num| {
+                    num.parse().map_err(|_| ParseError::ExpectedNumber {
+                        location: Location::of(input, num),
+                        found: String::from(num),
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn part1(reports: &Input) -> Answer {
+    reports
+        .iter()
+        .filter(|&report| is_safe(report))
+        .count()
+        .into()
+}
+
+pub fn part2(reports: &Input) -> Answer {
+    reports
+        .iter()
+        .filter(|&report| removed_levels(report, &Tolerance::DAMPENER).is_some())
+        .count()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(2));
+        assert_eq!(part2(&input), Answer::Number(4));
+    }
+
+    #[test]
+    fn sample_diagnostics() {
+        let input = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+        let input = parse(input).unwrap();
+
+        assert_eq!(
+            input
+                .iter()
+                .map(|report| diagnose(report))
+                .collect::<Vec<_>>(),
+            [
+                Verdict::Safe,
+                Verdict::StepTooLarge { index: 1, step: 5 },
+                Verdict::StepTooLarge { index: 2, step: 4 },
+                Verdict::DirectionChange { index: 1 },
+                Verdict::NoChange { index: 2 },
+                Verdict::Safe,
+            ]
+        );
+        assert_eq!(diagnose(&[3, 3, 2]), Verdict::NoChange { index: 0 });
+        assert!(diagnostics(&input).ends_with(
+            "6: 1 3 6 7 9: safe
+
+safe: 2
+direction change: 1
+step of 0: 1
+step larger than 3: 2
+"
+        ));
+    }
+
+    #[test]
+    fn removed_levels_per_tolerance() {
+        let strict = Tolerance {
+            max_removed: 0,
+            ..Tolerance::DAMPENER
+        };
+        let lenient = Tolerance {
+            max_removed: 2,
+            ..Tolerance::DAMPENER
+        };
+
+        assert_eq!(removed_levels(&[1, 3, 6, 7, 9], &strict), Some(vec![]));
+        assert_eq!(removed_levels(&[1, 3, 2, 4, 5], &strict), None);
+        assert_eq!(
+            removed_levels(&[1, 3, 2, 4, 5], &Tolerance::DAMPENER),
+            Some(vec![2])
+        );
+        assert_eq!(removed_levels(&[9, 1, 2, 2, 3], &Tolerance::DAMPENER), None);
+        assert_eq!(removed_levels(&[9, 1, 2, 2, 3], &lenient), Some(vec![0, 3]));
+        assert_eq!(removed_levels(&[5, 9, 9, 9, 1], &lenient), None);
+
+        let wide = Tolerance {
+            max_removed: 0,
+            min_step: 0,
+            max_step: 10,
+        };
+        assert_eq!(removed_levels(&[20, 20, 11, 1], &wide), Some(vec![]));
+    }
+
+    #[test]
+    fn dampener_matches_brute_force() {
+        let input = generate::generate(2, 500);
+        let input = parse(&input).unwrap();
+
+        for report in &input {
+            let brute_force = (0..report.len()).any(|i| {
+                let mut report = report.clone();
+                report.remove(i);
+                is_safe(&report)
+            });
+            assert_eq!(
+                removed_levels(report, &Tolerance::DAMPENER).is_some(),
+                brute_force,
+                "{report:?}"
+            );
+        }
+    }
+}
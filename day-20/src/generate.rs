@@ -0,0 +1,60 @@
+use common::Rng;
+use grid::{Grid, Point};
+
+pub const DEFAULT_SIZE: usize = 141;
+
+/// A `size` by `size` racetrack, rounded up to an odd size. The track is a
+/// single path without branches that winds between the walls, taken from the
+/// deepest point of a randomised depth first search over the odd tiles.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(5) | 1;
+
+    let start = Point::new(
+        rng.index(size / 2) as isize * 2 + 1,
+        rng.index(size / 2) as isize * 2 + 1,
+    );
+    let mut visited = Grid::new(size, size, false);
+    visited[start] = true;
+
+    let mut stack = vec![start];
+    let mut track = stack.clone();
+    while let Some(&position) = stack.last() {
+        let unvisited = [
+            Point::new(2, 0),
+            Point::new(-2, 0),
+            Point::new(0, 2),
+            Point::new(0, -2),
+        ]
+        .into_iter()
+        .map(|step| position + step)
+        .filter(|next| {
+            (1..size as isize - 1).contains(&next.x)
+                && (1..size as isize - 1).contains(&next.y)
+                && !visited[*next]
+        })
+        .collect::<Vec<_>>();
+
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let next = *rng.choose(&unvisited);
+        visited[next] = true;
+        stack.push(next);
+        if stack.len() > track.len() {
+            track.clone_from(&stack);
+        }
+    }
+
+    let mut grid = Grid::new(size, size, '#');
+    for pair in track.windows(2) {
+        grid[pair[0]] = '.';
+        grid[Point::new((pair[0].x + pair[1].x) / 2, (pair[0].y + pair[1].y) / 2)] = '.';
+    }
+    grid[track[0]] = 'S';
+    grid[track[track.len() - 1]] = 'E';
+
+    format!("{grid}\n")
+}
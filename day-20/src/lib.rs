@@ -0,0 +1,195 @@
+use std::{error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+use grid::{Grid, Image, Point, Rgb};
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Grid(grid::ParseError),
+    MissingTile { location: Location, expected: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Grid(err) => write!(f, "{err}"),
+            ParseError::MissingTile { expected, .. } => {
+                write!(f, "expected a {expected:?} tile in the grid")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::Grid(err) => err.location(),
+            ParseError::MissingTile { location, .. } => *location,
+        }
+    }
+}
+
+impl From<grid::ParseError> for ParseError {
+    fn from(err: grid::ParseError) -> Self {
+        ParseError::Grid(err)
+    }
+}
+
+pub struct Input {
+    grid: Grid<char>,
+    min_saving: isize,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let grid = Grid::try_parse(input, "'#', '.', 'S' or 'E'", |c| {
+        ['#', '.', 'S', 'E'].contains(&c).then_some(c)
+    })?;
+    if grid.find(&'S').is_none() {
+        return Err(ParseError::MissingTile {
+            location: Location::end_of(input),
+            expected: 'S',
+        });
+    }
+
+    Ok(Input {
+        grid,
+        min_saving: 100,
+    })
+}
+
+/// Parse the sample from the puzzle text, in which no cheat saves 100
+/// picoseconds. The puzzle text counts the cheats that save at least 50
+/// picoseconds for part 2, which is used for both parts.
+pub fn parse_sample(input: &str) -> Result<Input, ParseError> {
+    Ok(Input {
+        min_saving: 50,
+        ..parse(input)?
+    })
+}
+
+fn race_track_cost(grid: &Grid<char>) -> Grid<Option<isize>> {
+    let start = grid.find(&'S').unwrap();
+
+    let mut cost = grid.map(|_| None);
+    let mut position = start;
+    let mut step = 0;
+    loop {
+        cost[position] = Some(step);
+        step += 1;
+
+        let new_position = grid
+            .neighbours4(position)
+            .find(|neighbour| grid[*neighbour] != '#' && cost[*neighbour].is_none());
+        match new_position {
+            Some(new_pos) => position = new_pos,
+            None => break,
+        }
+    }
+
+    cost
+}
+
+pub fn part1(input: &Input) -> Answer {
+    let grid = &input.grid;
+    let cost = race_track_cost(grid);
+
+    grid.positions(|c| *c == '#')
+        .filter_map(|position| {
+            let neighbour_costs = grid
+                .neighbours4(position)
+                .filter_map(|neighbour| cost[neighbour])
+                .collect::<Vec<_>>();
+
+            if neighbour_costs.len() >= 2 {
+                Some(
+                    *neighbour_costs.iter().max().unwrap()
+                        - *neighbour_costs.iter().min().unwrap()
+                        - 2,
+                )
+            } else {
+                None
+            }
+        })
+        .filter(|n| *n >= input.min_saving)
+        .count()
+        .into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    let cost = race_track_cost(&input.grid);
+
+    cost.iter()
+        .filter_map(|(position, c)| c.map(|c| (position, c)))
+        .flat_map(|(start, start_cost)| {
+            let cost = &cost;
+            (2..21)
+                .flat_map(|steps| (0..(steps + 1)).map(move |dy| Point::new(steps - dy, dy)))
+                .flat_map(move |offset| {
+                    [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+                        .into_iter()
+                        .filter(move |(cx, cy)| {
+                            // Don't count the same end point twice on the axes
+                            !((offset.x == 0 && *cx == -1) || (offset.y == 0 && *cy == -1))
+                        })
+                        .filter_map(move |(cx, cy)| {
+                            let end = start + Point::new(offset.x * cx, offset.y * cy);
+                            let end_cost = (*cost.get(end)?)?;
+                            (end_cost >= start_cost)
+                                .then(|| end_cost - start_cost - offset.x - offset.y)
+                        })
+                })
+        })
+        .filter(|n| *n >= input.min_saving)
+        .count()
+        .into()
+}
+
+/// The racetrack from green at the start to red at the end, with a line
+/// along it
+pub fn image(input: &Input) -> Image {
+    let cost = race_track_cost(&input.grid);
+    let length = cost
+        .iter()
+        .filter_map(|(_, c)| *c)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let mut track = cost
+        .iter()
+        .filter_map(|(position, c)| c.map(|c| (c, position)))
+        .collect::<Vec<_>>();
+    track.sort();
+
+    let mut image = Image::new(&cost, |_, c| match c {
+        Some(c) => Rgb::GREEN.blend(Rgb::RED, *c as f64 / length as f64),
+        None => Rgb::GREY,
+    });
+    image.path(
+        track.into_iter().map(|(_, position)| position).collect(),
+        Rgb::WHITE,
+    );
+
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+
+        let mut input = parse_sample(input).unwrap();
+        assert_eq!(part1(&input), Answer::Number(1));
+        assert_eq!(part2(&input), Answer::Number(285));
+
+        input.min_saving = 20;
+        assert_eq!(part1(&input), Answer::Number(5));
+    }
+}
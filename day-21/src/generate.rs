@@ -0,0 +1,12 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 5;
+
+/// `size` door codes of three digits followed by `A`
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    (0..size)
+        .map(|_| format!("{:03}A\n", rng.below(1000)))
+        .collect()
+}
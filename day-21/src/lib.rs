@@ -1,8 +1,39 @@
 use core::panic;
-use std::{collections::BTreeMap, fs};
+use std::{collections::BTreeMap, error::Error, fmt};
 
+use common::{Answer, InputError, Location};
 use itertools::{repeat_n, Itertools};
 
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    ExpectedDigit { location: Location, found: char },
+    MissingActivate { location: Location },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::ExpectedDigit { found, .. } => {
+                write!(f, "expected a digit, found {found:?}")
+            }
+            ParseError::MissingActivate { .. } => write!(f, "expected the code to end with 'A'"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::ExpectedDigit { location, .. }
+            | ParseError::MissingActivate { location } => *location,
+        }
+    }
+}
+
 /*
 <vA<AA>>^AvAA<^A>A<v<A>>^AvA^A<vA>^A<v<A>^A>AAvA^A<v<A>A>^AAAvA<^A>A
   v <<   A >>  ^ A   <   A > A  v  A   <  ^ AA > A   < v  AAA >  ^ A
@@ -49,7 +80,7 @@ fn move_directional(c: char, position: (usize, usize)) -> (usize, usize) {
 }
 
 fn directional_sequence(
-    sequence: &Vec<char>,
+    sequence: &[char],
     level: usize,
     cache: &mut BTreeMap<(String, usize), usize>,
 ) -> usize {
@@ -70,8 +101,8 @@ fn directional_sequence(
             let x_button = if dx >= 0 { '>' } else { '<' };
             let y_button = if dy >= 0 { 'v' } else { '^' };
 
-            let path_length = repeat_n(x_button, dx.abs() as usize)
-                .chain(repeat_n(y_button, dy.abs() as usize))
+            let path_length = repeat_n(x_button, dx.unsigned_abs())
+                .chain(repeat_n(y_button, dy.unsigned_abs()))
                 .permutations((dx.abs() + dy.abs()) as usize)
                 .unique()
                 .filter(|path| {
@@ -107,7 +138,7 @@ fn directional_sequence(
 }
 
 fn keypad_sequence(
-    sequence: &Vec<char>,
+    sequence: &[char],
     directional_level: usize,
     cache: &mut BTreeMap<(String, usize), usize>,
 ) -> usize {
@@ -123,8 +154,8 @@ fn keypad_sequence(
             let x_button = if dx >= 0 { '>' } else { '<' };
             let y_button = if dy >= 0 { 'v' } else { '^' };
 
-            let path_length = repeat_n(x_button, dx.abs() as usize)
-                .chain(repeat_n(y_button, dy.abs() as usize))
+            let path_length = repeat_n(x_button, dx.unsigned_abs())
+                .chain(repeat_n(y_button, dy.unsigned_abs()))
                 .permutations((dx.abs() + dy.abs()) as usize)
                 .unique()
                 .filter(|path| {
@@ -151,68 +182,78 @@ fn keypad_sequence(
         .sum()
 }
 
-fn run(input: &str) {
-    let codes = input
-        .lines()
-        .map(|code| code.chars().map(|c| c).collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+pub type Input = Vec<Vec<char>>;
 
-    let complexity_sum = codes
-        .iter()
+// Codes are digits followed by 'A', like "029A"
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    input
+        .lines()
         .map(|code| {
-            let sequence_length = keypad_sequence(code, 1, &mut BTreeMap::new());
-            let numeric_part_code = code[..(code.len() - 1)]
-                .into_iter()
-                .collect::<String>()
-                .parse::<usize>()
-                .unwrap();
+            let digits = code
+                .strip_suffix('A')
+                .ok_or_else(|| ParseError::MissingActivate {
+                    location: Location::of(input, &code[code.len()..]),
+                })?;
+            if let Some((offset, found)) = digits.char_indices().find(|(_, c)| !c.is_ascii_digit())
+            {
+                return Err(ParseError::ExpectedDigit {
+                    location: Location::of(input, &digits[offset..]),
+                    found,
+                });
+            }
+            if digits.is_empty() {
+                return Err(ParseError::ExpectedDigit {
+                    location: Location::of(input, code),
+                    found: 'A',
+                });
+            }
 
-            sequence_length * numeric_part_code
+            Ok(code.chars().collect::<Vec<_>>())
         })
-        .sum::<usize>();
-    println!("Sum of complexity codes first historian: {complexity_sum}");
+        .collect()
+}
 
-    let complexity_sum = codes
+fn complexity_sum(codes: &[Vec<char>], directional_level: usize) -> usize {
+    codes
         .iter()
         .map(|code| {
-            let sequence_length = keypad_sequence(code, 24, &mut BTreeMap::new());
+            let sequence_length = keypad_sequence(code, directional_level, &mut BTreeMap::new());
             let numeric_part_code = code[..(code.len() - 1)]
-                .into_iter()
+                .iter()
                 .collect::<String>()
                 .parse::<usize>()
                 .unwrap();
 
             sequence_length * numeric_part_code
         })
-        .sum::<usize>();
-    println!("Sum of complexity codes second historian: {complexity_sum}");
+        .sum::<usize>()
+}
+
+pub fn part1(codes: &Input) -> Answer {
+    complexity_sum(codes, 1).into()
 }
 
-fn main() {
-    let input = fs::read_to_string("input").unwrap();
-    run(&input);
+pub fn part2(codes: &Input) -> Answer {
+    complexity_sum(codes, 24).into()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    // Run with cargo test -- --nocapture
     #[test]
     fn sample_input_1() {
-        let input = "029A
-980A
-179A
-456A
-379A";
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
 
-        run(&input);
+        assert_eq!(part1(&input), Answer::Number(126384));
+        assert_eq!(part2(&input), Answer::Number(154115708116294));
     }
 
     #[test]
     fn sample_input_2() {
         let input = "029A";
 
-        run(&input);
+        assert_eq!(part1(&parse(input).unwrap()), Answer::Number(68 * 29));
     }
 }
@@ -0,0 +1,13 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 2313;
+
+/// The initial secret numbers of `size` buyers, all below 2^24 like the
+/// secret numbers the buyers generate themselves
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    (0..size)
+        .map(|_| format!("{}\n", rng.between(1, (1 << 24) - 1)))
+        .collect()
+}
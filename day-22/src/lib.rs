@@ -0,0 +1,143 @@
+use std::{collections::BTreeMap, error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+use itertools::Itertools;
+
+pub mod generate;
+
+fn prng(mut secret: u64) -> u64 {
+    secret ^= secret << 6;
+    secret %= 16777216;
+    secret ^= secret >> 5;
+    secret %= 16777216;
+    secret ^= secret << 11;
+    secret %= 16777216;
+    secret
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    ExpectedNumber { location: Location, found: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::ExpectedNumber { found, .. } => {
+                write!(f, "expected a secret number, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::ExpectedNumber { location, .. } => *location,
+        }
+    }
+}
+
+pub type Input = Vec<u64>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    input
+        .lines()
+        .map(|n| {
+            n.parse::<u64>().map_err(|_| ParseError::ExpectedNumber {
+                location: Location::of(input, n),
+                found: String::from(n),
+            })
+        })
+        .collect()
+}
+
+pub fn part1(initial_secret_numbers: &Input) -> Answer {
+    initial_secret_numbers
+        .iter()
+        .map(|secret_start| {
+            let mut secret = *secret_start;
+            for _ in 0..2000 {
+                secret = prng(secret);
+            }
+            secret
+        })
+        .sum::<u64>()
+        .into()
+}
+
+pub fn part2(initial_secret_numbers: &Input) -> Answer {
+    let mut lookup = BTreeMap::<(i8, i8, i8, i8), Vec<Option<u64>>>::new();
+    for (i, secret_start) in initial_secret_numbers.iter().enumerate() {
+        let mut secret = *secret_start;
+
+        (0..2001)
+            .map(|_| {
+                let a = (secret % 10) as i8;
+                secret = prng(secret);
+                a
+            })
+            .tuple_windows()
+            .map(|(a, b)| (b, b - a))
+            .tuple_windows()
+            .for_each(|((_, a), (_, b), (_, c), (bananas, d))| {
+                let entry = lookup
+                    .entry((a, b, c, d))
+                    .or_insert(vec![None; initial_secret_numbers.len()]);
+                if entry[i].is_none() {
+                    entry[i] = Some(bananas as u64);
+                }
+            });
+    }
+
+    lookup
+        .values()
+        .map(|v| v.iter().filter_map(|a| *a).sum::<u64>())
+        .max()
+        .unwrap()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+
+        assert_eq!(part1(&parse(input).unwrap()), Answer::Number(37327623));
+    }
+
+    #[test]
+    fn sample_input_2() {
+        let input = "123";
+
+        assert_eq!(part2(&parse(input).unwrap()), Answer::Number(9));
+    }
+
+    #[test]
+    fn sample_input_3() {
+        let input = "1
+2
+3
+2024";
+
+        assert_eq!(part2(&parse(input).unwrap()), Answer::Number(23));
+    }
+
+    #[test]
+    fn custom_test() {
+        let mut secret = 123u64;
+        let seq = [
+            15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+            5908254,
+        ];
+        for n in seq {
+            secret = prng(secret);
+            assert_eq!(n, secret);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::BTreeSet;
+
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 520;
+
+// Every computer has this many connections on average and there is one LAN
+// party of this size hidden among them
+const CONNECTIONS: usize = 13;
+
+/// A random network of `size` computers, at most 676 as names are two
+/// letters. Next to random connections it contains one fully connected LAN
+/// party of 13 computers.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let mut names = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(2, names.len()));
+
+    let mut connections = BTreeSet::new();
+    let party = CONNECTIONS.min(names.len());
+    for a in 0..party {
+        for b in a + 1..party {
+            connections.insert((a, b));
+        }
+    }
+    let total = (names.len() * CONNECTIONS / 2).min(names.len() * (names.len() - 1) / 2);
+    while connections.len() < total {
+        let (a, b) = (rng.index(names.len()), rng.index(names.len()));
+        if a != b {
+            connections.insert((a.min(b), a.max(b)));
+        }
+    }
+
+    let mut lines = connections
+        .iter()
+        .map(|&(a, b)| {
+            if rng.chance(0.5) {
+                format!("{}-{}\n", names[a], names[b])
+            } else {
+                format!("{}-{}\n", names[b], names[a])
+            }
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut lines);
+
+    lines.concat()
+}
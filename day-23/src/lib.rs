@@ -0,0 +1,160 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    error::Error,
+    fmt,
+};
+
+use common::{Answer, InputError, Location};
+use itertools::Itertools;
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    ExpectedConnection { location: Location, found: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::ExpectedConnection { found, .. } => write!(
+                f,
+                "expected two computers joined by '-', like kh-tc, found {found:?}"
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::ExpectedConnection { location, .. } => *location,
+        }
+    }
+}
+
+fn max_clique<'a>(
+    connections: &HashMap<&'a str, BTreeSet<&'a str>>,
+    in_clique: BTreeSet<&'a str>,
+    mut possible_clique: BTreeSet<&'a str>,
+) -> BTreeSet<&'a str> {
+    if possible_clique.is_empty() {
+        return in_clique;
+    }
+
+    let mut largest_clique = BTreeSet::new();
+    while let Some(node) = possible_clique.pop_first() {
+        let node_set = max_clique(
+            connections,
+            in_clique
+                .union(&BTreeSet::from_iter([node]))
+                .copied()
+                .collect(),
+            possible_clique
+                .intersection(&connections[node])
+                .copied()
+                .collect(),
+        );
+
+        largest_clique = if node_set.len() > largest_clique.len() {
+            node_set
+        } else {
+            largest_clique
+        };
+    }
+
+    largest_clique
+}
+
+pub type Input<'a> = HashMap<&'a str, BTreeSet<&'a str>>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    let mut connections = Input::new();
+    for line in input.lines() {
+        let (a, b) = line
+            .split_once('-')
+            .filter(|(a, b)| !a.is_empty() && !b.is_empty())
+            .ok_or_else(|| ParseError::ExpectedConnection {
+                location: Location::of(input, line),
+                found: String::from(line),
+            })?;
+        connections.entry(a).or_default().insert(b);
+        connections.entry(b).or_default().insert(a);
+    }
+
+    Ok(connections)
+}
+
+pub fn part1(connections: &Input) -> Answer {
+    connections
+        .iter()
+        .filter(|(computer, _)| computer.starts_with('t'))
+        .flat_map(|(computer_a, local_connections)| {
+            local_connections
+                .iter()
+                .permutations(2)
+                .filter(|computers| connections[computers[0]].contains(computers[1]))
+                .map(|mut computers| {
+                    computers.push(computer_a);
+                    computers.sort();
+                    (*computers[0], *computers[1], *computers[2])
+                })
+        })
+        .unique()
+        .count()
+        .into()
+}
+
+pub fn part2(connections: &Input) -> Answer {
+    let mut banned_set = BTreeSet::new();
+    connections
+        .iter()
+        .map(|(computer, local_connections)| {
+            let max_c = max_clique(
+                connections,
+                BTreeSet::from_iter([*computer]),
+                local_connections.difference(&banned_set).copied().collect(),
+            );
+            banned_set.insert(*computer);
+            max_c
+        })
+        .max_by_key(|network| network.len())
+        .unwrap()
+        .into_iter()
+        .join(",")
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(7));
+        assert_eq!(part2(&input), Answer::Text(String::from("co,de,ka,ta")));
+    }
+
+    #[test]
+    fn sample_input_2() {
+        let input = "ka-co
+ta-co
+de-co
+ta-ka
+de-ta
+ka-de
+ja-co
+ha-rm
+ha-ja";
+
+        assert_eq!(
+            part2(&parse(input).unwrap()),
+            Answer::Text(String::from("co,de,ka,ta"))
+        );
+    }
+}
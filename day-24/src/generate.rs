@@ -0,0 +1,114 @@
+use std::collections::BTreeSet;
+
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 45;
+
+/// How many pairs of gates get their output wires swapped by `generate`
+pub const DEFAULT_SWAPS: usize = 4;
+
+// The pairs of gates of a full adder, as indices into its five gates below,
+// whose outputs can be swapped without creating a loop and so that the sum
+// comes out wrong. Swapping the outputs of the two AND gates for example
+// changes nothing, as both only feed the OR gate.
+const BREAKING_SWAPS: [(usize, usize); 4] = [(0, 1), (1, 2), (2, 3), (2, 4)];
+
+struct Gate {
+    a: String,
+    operation: &'static str,
+    b: String,
+    out: String,
+}
+
+/// A ripple-carry adder of two `size` bit numbers, at most 99, with random
+/// input bits and random names for the internal wires. In four full adders
+/// the outputs of two gates are swapped, see `generate_with_swaps`.
+pub fn generate(seed: u64, size: usize) -> String {
+    generate_with_swaps(seed, size, DEFAULT_SWAPS).0
+}
+
+/// Like `generate`, but with the outputs of two gates swapped in `swaps`
+/// different full adders, as many as there are. Also returns the wires that
+/// were swapped, which is what part 2 has to find.
+pub fn generate_with_swaps(seed: u64, size: usize, swaps: usize) -> (String, BTreeSet<String>) {
+    let mut rng = Rng::new(seed);
+    let bits = size.clamp(2, 99);
+
+    let mut names = BTreeSet::new();
+    let mut wire = |rng: &mut Rng| loop {
+        let name = (0..3)
+            .map(|_| char::from(b'a' + rng.below(23) as u8))
+            .collect::<String>();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+    let gate = |a: &str, operation, b: &str, out: String| Gate {
+        a: String::from(a),
+        operation,
+        b: String::from(b),
+        out,
+    };
+
+    let mut gates = vec![
+        gate("x00", "XOR", "y00", String::from("z00")),
+        gate("x00", "AND", "y00", wire(&mut rng)),
+    ];
+    // The indices of the five gates of every full adder
+    let mut full_adders = Vec::new();
+    for bit in 1..bits {
+        let (x, y, z) = (
+            format!("x{bit:02}"),
+            format!("y{bit:02}"),
+            format!("z{bit:02}"),
+        );
+        let carry_in = gates[gates.len() - 1].out.clone();
+        let carry_out = if bit == bits - 1 {
+            format!("z{bits:02}")
+        } else {
+            wire(&mut rng)
+        };
+        let (sum, carry, both) = (wire(&mut rng), wire(&mut rng), wire(&mut rng));
+
+        full_adders.push(gates.len()..gates.len() + 5);
+        gates.extend([
+            gate(&x, "XOR", &y, sum.clone()),
+            gate(&x, "AND", &y, both.clone()),
+            gate(&sum, "XOR", &carry_in, z),
+            gate(&sum, "AND", &carry_in, carry.clone()),
+            gate(&carry, "OR", &both, carry_out),
+        ]);
+    }
+
+    let mut swapped = BTreeSet::new();
+    rng.shuffle(&mut full_adders);
+    for full_adder in full_adders.iter().take(swaps) {
+        let (a, b) = BREAKING_SWAPS[rng.index(BREAKING_SWAPS.len())];
+        let (a, b) = (full_adder.start + a, full_adder.start + b);
+
+        let out = std::mem::take(&mut gates[a].out);
+        gates[a].out = std::mem::replace(&mut gates[b].out, out);
+        swapped.extend([gates[a].out.clone(), gates[b].out.clone()]);
+    }
+
+    let initial_values = ['x', 'y']
+        .iter()
+        .flat_map(|register| (0..bits).map(move |bit| format!("{register}{bit:02}")))
+        .map(|wire| format!("{wire}: {}\n", rng.below(2)))
+        .collect::<String>();
+
+    rng.shuffle(&mut gates);
+    let gates = gates
+        .iter()
+        .map(|gate| {
+            let (a, b) = if rng.chance(0.5) {
+                (&gate.a, &gate.b)
+            } else {
+                (&gate.b, &gate.a)
+            };
+            format!("{a} {} {b} -> {}\n", gate.operation, gate.out)
+        })
+        .collect::<String>();
+
+    (format!("{initial_values}\n{gates}"), swapped)
+}
@@ -0,0 +1,523 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    error::Error,
+    fmt,
+    str::FromStr,
+};
+
+use common::{Answer, InputError, Location};
+use itertools::Itertools;
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    MissingGates { location: Location },
+    ExpectedWireValue { location: Location, found: String },
+    ExpectedGate { location: Location, found: String },
+    UnknownOperation { location: Location, found: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingGates { .. } => {
+                write!(f, "expected an empty line followed by the gates")
+            }
+            ParseError::ExpectedWireValue { found, .. } => {
+                write!(f, "expected a wire value like \"x00: 1\", found {found:?}")
+            }
+            ParseError::ExpectedGate { found, .. } => write!(
+                f,
+                "expected a gate like \"x00 AND y00 -> z00\", found {found:?}"
+            ),
+            ParseError::UnknownOperation { found, .. } => {
+                write!(f, "expected AND, OR or XOR, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::MissingGates { location }
+            | ParseError::ExpectedWireValue { location, .. }
+            | ParseError::ExpectedGate { location, .. }
+            | ParseError::UnknownOperation { location, .. } => *location,
+        }
+    }
+}
+
+/// Why the gates aren't an adder with only some outputs swapped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdderError {
+    MissingInputBits,
+    GateCount {
+        gates: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    // A wire of the adder no gate reads from or writes to
+    MissingWire {
+        wire: String,
+    },
+    // A gate on the x and y wires that isn't `xNN XOR yNN` or `xNN AND yNN`
+    UnexpectedGate {
+        gate: String,
+    },
+}
+
+impl fmt::Display for AdderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdderError::MissingInputBits => write!(f, "there are no x wires to add"),
+            AdderError::GateCount {
+                gates,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {expected} {gates} for the adder, found {found}"
+            ),
+            AdderError::MissingWire { wire } => write!(f, "no gate is attached to {wire}"),
+            AdderError::UnexpectedGate { gate } => {
+                write!(f, "expected a gate of a half adder, found {gate:?}")
+            }
+        }
+    }
+}
+
+impl Error for AdderError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+enum Operation {
+    AND,
+    OR,
+    XOR,
+}
+
+impl FromStr for Operation {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AND" => Ok(Operation::AND),
+            "OR" => Ok(Operation::OR),
+            "XOR" => Ok(Operation::XOR),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Operation {
+    fn execute(&self, a: bool, b: bool) -> bool {
+        match self {
+            Operation::OR => a || b,
+            Operation::AND => a && b,
+            Operation::XOR => a ^ b,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Gate {
+    in_a: String,
+    in_b: String,
+    out: String,
+    operation: Operation,
+}
+
+impl fmt::Display for Gate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:?} {} -> {}",
+            self.in_a, self.operation, self.in_b, self.out
+        )
+    }
+}
+
+impl Gate {
+    fn other_input(&self, input: &String) -> &String {
+        if *input == *self.in_a {
+            &self.in_b
+        } else {
+            &self.in_a
+        }
+    }
+
+    fn parse(input: &str, line: &str) -> Result<Gate, ParseError> {
+        let expected_gate = || ParseError::ExpectedGate {
+            location: Location::of(input, line),
+            found: String::from(line),
+        };
+        let (left, op, right, arrow, res) = line
+            .split_ascii_whitespace()
+            .collect_tuple()
+            .ok_or_else(expected_gate)?;
+        if arrow != "->" {
+            return Err(expected_gate());
+        }
+
+        Ok(Gate {
+            in_a: String::from(left),
+            in_b: String::from(right),
+            out: String::from(res),
+            operation: op.parse().map_err(|_| ParseError::UnknownOperation {
+                location: Location::of(input, op),
+                found: String::from(op),
+            })?,
+        })
+    }
+}
+
+fn create_input_map(gates: &[Gate]) -> BTreeMap<String, Vec<usize>> {
+    gates
+        .iter()
+        .enumerate()
+        .flat_map(|(i, gate)| [(gate.in_a.clone(), i), (gate.in_b.clone(), i)])
+        .fold(
+            BTreeMap::new(),
+            |mut acc: BTreeMap<String, Vec<_>>, (wire, gate)| {
+                acc.entry(wire).or_default().push(gate);
+                acc
+            },
+        )
+}
+
+fn create_output_map(gates: &[Gate]) -> BTreeMap<String, usize> {
+    gates
+        .iter()
+        .enumerate()
+        .map(|(i, gate)| (gate.out.clone(), i))
+        .collect()
+}
+
+fn simulate_circuit(
+    gates: &[Gate],
+    mut wire_values: BTreeMap<String, bool>,
+) -> BTreeMap<String, bool> {
+    let gates_from_input = create_input_map(gates);
+
+    let mut wire_queue = VecDeque::from_iter(
+        wire_values
+            .iter()
+            .map(|(wire, value)| (wire.clone(), *value)),
+    );
+
+    while let Some((wire, value)) = wire_queue.pop_front() {
+        if let Some(gates_indices) = gates_from_input.get(&wire) {
+            for gate_index in gates_indices {
+                let gate = &gates[*gate_index];
+                let other_wire = if gate.in_a == wire {
+                    gate.in_b.clone()
+                } else {
+                    gate.in_a.clone()
+                };
+                if let Some(other_value) = wire_values.get(&other_wire) {
+                    let operation_result = gate.operation.execute(value, *other_value);
+                    wire_values.insert(gate.out.clone(), operation_result);
+                    wire_queue.push_back((gate.out.clone(), operation_result));
+                }
+            }
+        }
+    }
+
+    wire_values
+}
+
+fn extract_number_from_wires(wire_values: &BTreeMap<String, bool>, prefix: char) -> u64 {
+    wire_values
+        .iter()
+        .filter(|(wire, _)| wire.chars().next().unwrap() == prefix)
+        .rev()
+        .fold(0u64, |acc, (_, n)| (acc << 1) | (if *n { 1 } else { 0 }))
+}
+
+/*
+z00 = x00 ^ z00
+c00 = x00 & y00
+
+for n in 1..45 {
+    a_n = x_n ^ y_n
+    b_n = x_n & y_n
+
+    d_n = a_n & c_(n-1)
+    z_n = a_n ^ c_(n-1)
+
+    c_n = d_n | b_n
+}
+
+z45 = c44
+*/
+
+#[derive(Debug, Copy, Clone)]
+enum Alias {
+    A,
+    B,
+    Z,
+}
+
+fn find_swaps(gates: &[Gate]) -> Result<BTreeSet<String>, AdderError> {
+    let gates_from_input = create_input_map(gates);
+    let gate_from_output = create_output_map(gates);
+
+    let bits = gates_from_input
+        .keys()
+        .filter(|wire| wire.starts_with('x'))
+        .count();
+    // The carry out of the last full adder, which is the highest output bit
+    let last_z = format!("z{bits:02}");
+
+    let mut swaps = BTreeSet::new();
+    let mut gate_alias_map = vec![None; gates.len()];
+
+    // Mark all a and b gates
+    for i in 0..bits {
+        let x = format!("x{:02}", i);
+        let y = format!("y{:02}", i);
+
+        let x_gates = gates_from_input
+            .get(&x)
+            .ok_or(AdderError::MissingWire { wire: x.clone() })?;
+
+        for gate_index in x_gates {
+            let gate = &gates[*gate_index];
+            let unexpected = || AdderError::UnexpectedGate {
+                gate: gate.to_string(),
+            };
+            if *gate.other_input(&x) != y {
+                return Err(unexpected());
+            }
+
+            gate_alias_map[*gate_index] = Some(match gate.operation {
+                Operation::XOR => Alias::A,
+                Operation::AND => Alias::B,
+                Operation::OR => return Err(unexpected()),
+            });
+        }
+    }
+
+    // Mark z outputs not bound to XOR
+    for i in 1..bits {
+        let z = format!("z{:02}", i);
+        let z_gate_index = gate_from_output
+            .get(&z)
+            .ok_or(AdderError::MissingWire { wire: z.clone() })?;
+        let z_gate = &gates[*z_gate_index];
+        if z_gate.operation != Operation::XOR {
+            swaps.insert(z.clone());
+        } else {
+            gate_alias_map[*z_gate_index] = Some(Alias::Z);
+        }
+    }
+
+    // Mark all wires attached to a XOR that isn't marked as an alias yet
+    for (i, gate) in gates.iter().enumerate() {
+        if gate.operation == Operation::XOR && gate_alias_map[i].is_none() {
+            swaps.insert(gate.out.clone());
+        }
+    }
+
+    // Mark all wires out of an A XOR that isn't a net attached an AND and XOR a swap
+    for (i, gate) in gates.iter().enumerate() {
+        // The half adder of bit 0 has z00 as sum and its carry goes to both
+        // gates of the next full adder, the last carry is the highest z
+        let half_adder = gate.in_a == "x00" || gate.in_a == "y00";
+        if half_adder || gate.out == last_z {
+            continue;
+        }
+
+        if gate.operation == Operation::OR
+            || (gate.operation == Operation::XOR && matches!(gate_alias_map[i], Some(Alias::A)))
+        {
+            if let Some(net_gates_indices) = gates_from_input.get(&gate.out) {
+                if net_gates_indices.len() != 2 {
+                    swaps.insert(gate.out.clone());
+                }
+            } else {
+                swaps.insert(gate.out.clone());
+            }
+        }
+
+        if gate.operation == Operation::AND {
+            if let Some(net_gates_indices) = gates_from_input.get(&gate.out) {
+                if net_gates_indices.len() != 1 {
+                    swaps.insert(gate.out.clone());
+                }
+            }
+        }
+    }
+
+    Ok(swaps)
+}
+
+pub struct Input {
+    initial_values: BTreeMap<String, bool>,
+    gates: Vec<Gate>,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let (input_static, input_gates) =
+        input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::MissingGates {
+                location: Location::end_of(input),
+            })?;
+
+    let initial_values = input_static
+        .lines()
+        .map(|line| match line.split_once(": ") {
+            Some((wire, "0")) => Ok((String::from(wire), false)),
+            Some((wire, "1")) => Ok((String::from(wire), true)),
+            _ => Err(ParseError::ExpectedWireValue {
+                location: Location::of(input, line),
+                found: String::from(line),
+            }),
+        })
+        .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+    let gates = input_gates
+        .lines()
+        .map(|line| Gate::parse(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Input {
+        initial_values,
+        gates,
+    })
+}
+
+pub fn part1(input: &Input) -> Answer {
+    let final_wire_values = simulate_circuit(&input.gates, input.initial_values.clone());
+
+    extract_number_from_wires(&final_wire_values, 'z').into()
+}
+
+pub fn part2(input: &Input) -> Result<Answer, AdderError> {
+    let gates = &input.gates;
+    let full_adders = input
+        .initial_values
+        .keys()
+        .filter(|wire| wire.starts_with('x'))
+        .count()
+        .checked_sub(1)
+        .ok_or(AdderError::MissingInputBits)?;
+
+    // A half adder has a XOR and an AND gate, a full adder two of each and
+    // an OR gate
+    let count = |operation| {
+        gates
+            .iter()
+            .filter(|gate| gate.operation == operation)
+            .count()
+    };
+    for (kind, per_half_adder, per_full_adder, found) in [
+        ("gates", 2, 5, gates.len()),
+        ("XOR gates", 1, 2, count(Operation::XOR)),
+        ("AND gates", 1, 2, count(Operation::AND)),
+        ("OR gates", 0, 1, count(Operation::OR)),
+    ] {
+        let expected = per_half_adder + full_adders * per_full_adder;
+        if found != expected {
+            return Err(AdderError::GateCount {
+                gates: kind,
+                expected,
+                found,
+            });
+        }
+    }
+
+    Ok(find_swaps(gates)?.iter().join(",").into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+
+        // The sample isn't an adder so only the simulation applies
+        assert_eq!(part1(&parse(input).unwrap()), Answer::Number(9));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00").err(),
+            Some(ParseError::UnknownOperation {
+                location: Location::new(4, 5),
+                found: String::from("NAND"),
+            })
+        );
+        assert_eq!(
+            parse("x00: 1\ny00: 0\n\nx00 AND y00 z00").err(),
+            Some(ParseError::ExpectedGate {
+                location: Location::new(4, 1),
+                found: String::from("x00 AND y00 z00"),
+            })
+        );
+    }
+
+    #[test]
+    fn generated_input() {
+        for seed in 0..20 {
+            for (size, swaps) in [(45, 4), (45, 0), (10, 2), (99, 9)] {
+                let (input, swapped) = generate::generate_with_swaps(seed, size, swaps);
+                let input = parse(&input).unwrap();
+
+                assert_eq!(swapped.len(), 2 * swaps);
+                if swaps == 0 {
+                    let x = extract_number_from_wires(&input.initial_values, 'x');
+                    let y = extract_number_from_wires(&input.initial_values, 'y');
+                    assert_eq!(part1(&input), Answer::Number(x + y));
+                }
+                assert_eq!(
+                    part2(&input),
+                    Ok(swapped.iter().join(",").into()),
+                    "seed {seed}, size {size}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn not_an_adder() {
+        let part2_of = |input| part2(&parse(input).unwrap());
+
+        assert_eq!(
+            part2_of("y00: 1\n\ny00 AND y00 -> z00"),
+            Err(AdderError::MissingInputBits)
+        );
+        assert_eq!(
+            part2_of("x00: 1\n\nx00 AND x00 -> z00"),
+            Err(AdderError::GateCount {
+                gates: "gates",
+                expected: 2,
+                found: 1,
+            })
+        );
+        assert_eq!(
+            part2_of("x00: 1\ny00: 1\n\nx00 XOR x00 -> z00\nx00 AND y00 -> z01"),
+            Err(AdderError::UnexpectedGate {
+                gate: String::from("x00 XOR x00 -> z00"),
+            })
+        );
+        assert_eq!(
+            part2_of(
+                "x00: 1\nx01: 1\ny00: 1\ny01: 1\n\nx00 XOR y00 -> z00\nx00 AND y00 -> c00\n\
+                 x01 XOR y01 -> a01\nx01 AND y01 -> b01\na01 XOR c00 -> s01\n\
+                 a01 AND c00 -> d01\nd01 OR b01 -> z02"
+            ),
+            Err(AdderError::MissingWire {
+                wire: String::from("z01"),
+            })
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 500;
+
+/// `size` schematics of five pins, each a lock or a key with equal chance
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let schematics = (0..size.max(1))
+        .map(|_| {
+            let lock = rng.chance(0.5);
+            let heights = [(); 5].map(|_| rng.between(0, 5) as usize);
+
+            (0..7)
+                .map(|row| {
+                    heights
+                        .iter()
+                        .map(|height| {
+                            // Locks hang down from the top row, keys stand on the bottom one
+                            let depth = if lock { row } else { 6 - row };
+                            if depth <= *height {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .chain(['\n'])
+                        .collect::<String>()
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    schematics.join("\n")
+}
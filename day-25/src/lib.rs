@@ -0,0 +1,120 @@
+use std::{error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    ExpectedRows { location: Location, found: usize },
+    ExpectedColumns { location: Location, found: usize },
+    ExpectedPin { location: Location, found: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::ExpectedRows { found, .. } => {
+                write!(f, "expected a schematic of 7 rows, found {found} rows")
+            }
+            ParseError::ExpectedColumns { found, .. } => {
+                write!(f, "expected a row of 5 columns, found {found} columns")
+            }
+            ParseError::ExpectedPin { found, .. } => {
+                write!(f, "expected '#' or '.', found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::ExpectedRows { location, .. }
+            | ParseError::ExpectedColumns { location, .. }
+            | ParseError::ExpectedPin { location, .. } => *location,
+        }
+    }
+}
+
+pub struct Input {
+    keys: Vec<[usize; 5]>,
+    locks: Vec<[usize; 5]>,
+}
+
+fn check_schematic(input: &str, block: &str) -> Result<(), ParseError> {
+    let rows = block.lines().collect::<Vec<_>>();
+    if rows.len() != 7 {
+        return Err(ParseError::ExpectedRows {
+            location: Location::of(input, block),
+            found: rows.len(),
+        });
+    }
+
+    for row in rows {
+        if let Some((offset, found)) = row.char_indices().find(|(_, c)| !['#', '.'].contains(c)) {
+            return Err(ParseError::ExpectedPin {
+                location: Location::of(input, &row[offset..]),
+                found,
+            });
+        }
+        if row.len() != 5 {
+            return Err(ParseError::ExpectedColumns {
+                location: Location::of(input, row),
+                found: row.len(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut keys = Vec::new();
+    let mut locks = Vec::new();
+
+    for block in input.split("\n\n") {
+        check_schematic(input, block)?;
+
+        let matrix = block.lines().collect::<Vec<_>>();
+        let sequence: [usize; 5] =
+            std::array::from_fn(|x| (1..6).filter(|y| matrix[*y].as_bytes()[x] == b'#').count());
+
+        if block.starts_with('#') {
+            keys.push(sequence);
+        } else {
+            locks.push(sequence);
+        }
+    }
+
+    Ok(Input { keys, locks })
+}
+
+pub fn part1(input: &Input) -> Answer {
+    input
+        .keys
+        .iter()
+        .map(|key| {
+            input
+                .locks
+                .iter()
+                .filter(|lock| (0..5).all(|column| key[column] + lock[column] <= 5))
+                .count()
+        })
+        .sum::<usize>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+
+        assert_eq!(part1(&parse(input).unwrap()), Answer::Number(3));
+    }
+}
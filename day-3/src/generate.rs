@@ -0,0 +1,48 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 18000;
+
+const NOISE: &[u8] = b" !#$%&'()*+,-/:;<>?@[]^_{}~";
+const FUNCTIONS: [&str; 6] = ["what", "who", "where", "when", "from", "select"];
+
+/// Corrupted memory of about `size` characters, spread over lines of about
+/// 3000 characters. Next to valid instructions it contains instructions that
+/// are almost valid, like `mul(4*` or `mul ( 2 , 4 )`.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let mut memory = String::with_capacity(size + 32);
+    let mut line_length = 0;
+    while memory.len() < size {
+        let fragment = match rng.below(12) {
+            0..=2 => format!("mul({},{})", rng.between(1, 999), rng.between(1, 999)),
+            3 => String::from("do()"),
+            4 => String::from("don't()"),
+            5 => format!("mul({},{}]", rng.between(1, 999), rng.between(1, 999)),
+            6 => format!("mul ( {} , {} )", rng.between(1, 99), rng.between(1, 99)),
+            7 => format!("mul({}*", rng.between(1, 999)),
+            8 => format!("mul({},{})", rng.between(1000, 9999), rng.between(1, 999)),
+            9 => format!(
+                "{}({},{})",
+                rng.choose(&FUNCTIONS),
+                rng.between(1, 999),
+                rng.between(1, 999)
+            ),
+            _ => (0..rng.between(1, 6))
+                .map(|_| *rng.choose(NOISE) as char)
+                .collect(),
+        };
+
+        line_length += fragment.len();
+        memory.push_str(&fragment);
+        if line_length >= 3000 {
+            memory.push('\n');
+            line_length = 0;
+        }
+    }
+    if !memory.ends_with('\n') {
+        memory.push('\n');
+    }
+
+    memory
+}
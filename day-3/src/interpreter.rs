@@ -0,0 +1,262 @@
+use std::ops::Range;
+
+use crate::lexer::{Token, TokenKind};
+
+/// What running an instruction does
+#[derive(Debug, Clone, Copy)]
+pub enum Effect {
+    /// Add the result to the sum, but only while enabled
+    Value(fn(&[u32]) -> i64),
+    Enable,
+    Disable,
+}
+
+/// How many operands an instruction takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arity {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+impl Arity {
+    fn allows(self, operands: usize) -> bool {
+        match self {
+            Arity::Exactly(n) => operands == n,
+            Arity::AtLeast(n) => operands >= n,
+        }
+    }
+}
+
+/// An instruction like `mul(2,4)`: a name directly followed by operands of
+/// one to three digits between parentheses
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    pub name: &'static str,
+    pub arity: Arity,
+    pub effect: Effect,
+}
+
+pub const MUL: Instruction = Instruction {
+    name: "mul",
+    arity: Arity::Exactly(2),
+    effect: Effect::Value(|operands| operands.iter().map(|&n| n as i64).product()),
+};
+
+pub const DO: Instruction = Instruction {
+    name: "do",
+    arity: Arity::Exactly(0),
+    effect: Effect::Enable,
+};
+
+pub const DONT: Instruction = Instruction {
+    name: "don't",
+    arity: Arity::Exactly(0),
+    effect: Effect::Disable,
+};
+
+pub const ADD: Instruction = Instruction {
+    name: "add",
+    arity: Arity::AtLeast(1),
+    effect: Effect::Value(|operands| operands.iter().map(|&n| n as i64).sum()),
+};
+
+/// The first operand minus all others
+pub const SUB: Instruction = Instruction {
+    name: "sub",
+    arity: Arity::AtLeast(1),
+    effect: Effect::Value(|operands| {
+        operands[0] as i64 - operands[1..].iter().map(|&n| n as i64).sum::<i64>()
+    }),
+};
+
+/// An instruction found in the input
+#[derive(Debug, Clone)]
+pub struct Call<'a> {
+    pub instruction: &'a Instruction,
+    pub operands: Vec<u32>,
+    /// The bytes of the input from the first letter of the name up to and
+    /// including the closing parenthesis
+    pub span: Range<usize>,
+}
+
+/// An instruction as it was run
+#[derive(Debug, Clone)]
+pub struct Step<'a> {
+    pub call: Call<'a>,
+    /// Whether multiplications were enabled after the instruction ran
+    pub enabled: bool,
+    /// What was added to the sum, `None` for instructions that were skipped
+    /// or don't have a value
+    pub contribution: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Interpreter {
+    instructions: Vec<Instruction>,
+}
+
+impl Interpreter {
+    pub fn new() -> Interpreter {
+        Interpreter::default()
+    }
+
+    pub fn with(mut self, instruction: Instruction) -> Interpreter {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Only multiplications, part 1 of the puzzle
+    pub fn part1() -> Interpreter {
+        Interpreter::new().with(MUL)
+    }
+
+    /// Multiplications that can be switched off, part 2 of the puzzle
+    pub fn part2() -> Interpreter {
+        Interpreter::part1().with(DO).with(DONT)
+    }
+
+    // Garbage can be glued to the front of a name, so the instruction with
+    // the longest name the word ends with is picked
+    fn instruction(&self, word: &str) -> Option<&Instruction> {
+        self.instructions
+            .iter()
+            .filter(|instruction| word.ends_with(instruction.name))
+            .max_by_key(|instruction| instruction.name.len())
+    }
+
+    fn call_at<'a>(&'a self, tokens: &[Token], at: usize) -> Option<(Call<'a>, usize)> {
+        let TokenKind::Word(word) = tokens[at].kind else {
+            return None;
+        };
+        let instruction = self.instruction(word)?;
+        let start = tokens[at].span.end - instruction.name.len();
+
+        let mut rest = tokens[at + 1..].iter();
+        if rest.next()?.kind != TokenKind::Open {
+            return None;
+        }
+
+        let mut operands = Vec::new();
+        let end = loop {
+            let token = rest.next()?;
+            match token.kind {
+                TokenKind::Close if operands.is_empty() => break token.span.end,
+                TokenKind::Number(digits) if digits.len() <= 3 => {
+                    operands.push(digits.parse().unwrap());
+                }
+                _ => return None,
+            }
+
+            let token = rest.next()?;
+            match token.kind {
+                TokenKind::Comma => {}
+                TokenKind::Close => break token.span.end,
+                _ => return None,
+            }
+        };
+
+        if !instruction.arity.allows(operands.len()) {
+            return None;
+        }
+
+        let next = tokens.len() - rest.len();
+        Some((
+            Call {
+                instruction,
+                operands,
+                span: start..end,
+            },
+            next,
+        ))
+    }
+
+    /// Every instruction in `tokens` in order of appearance
+    pub fn calls<'a>(&'a self, tokens: &[Token]) -> Vec<Call<'a>> {
+        let mut calls = Vec::new();
+
+        let mut at = 0;
+        while at < tokens.len() {
+            match self.call_at(tokens, at) {
+                Some((call, next)) => {
+                    calls.push(call);
+                    at = next;
+                }
+                None => at += 1,
+            }
+        }
+
+        calls
+    }
+
+    /// Every instruction in `tokens` with what it did
+    pub fn trace<'a>(&'a self, tokens: &[Token]) -> Vec<Step<'a>> {
+        let mut enabled = true;
+
+        self.calls(tokens)
+            .into_iter()
+            .map(|call| {
+                let mut contribution = None;
+                match call.instruction.effect {
+                    Effect::Value(value) if enabled => contribution = Some(value(&call.operands)),
+                    Effect::Value(_) => {}
+                    Effect::Enable => enabled = true,
+                    Effect::Disable => enabled = false,
+                }
+
+                Step {
+                    call,
+                    enabled,
+                    contribution,
+                }
+            })
+            .collect()
+    }
+
+    /// The sum of all values of the instructions that ran while enabled
+    pub fn run(&self, tokens: &[Token]) -> i64 {
+        self.trace(tokens)
+            .iter()
+            .filter_map(|step| step.contribution)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tokenize;
+
+    #[test]
+    fn registered_instructions() {
+        let tokens = tokenize("add(1,2,3)mul(2,3)xsub(10,4,1)don't()add(100)do()sub(1)");
+
+        assert_eq!(Interpreter::part1().run(&tokens), 6);
+        assert_eq!(
+            Interpreter::new().with(ADD).with(SUB).run(&tokens),
+            6 + 5 + 100 + 1
+        );
+        assert_eq!(
+            Interpreter::part2().with(ADD).with(SUB).run(&tokens),
+            6 + 6 + 5 + 1
+        );
+    }
+
+    #[test]
+    fn call_spans() {
+        let tokens = tokenize("xmul(2,4)mul(1234,5)mul(3,)undo()");
+        let interpreter = Interpreter::part2();
+        let calls = interpreter.calls(&tokens);
+
+        assert_eq!(
+            calls
+                .iter()
+                .map(|call| (
+                    call.instruction.name,
+                    call.operands.clone(),
+                    call.span.clone()
+                ))
+                .collect::<Vec<_>>(),
+            [("mul", vec![2, 4], 1..9), ("do", vec![], 29..33)]
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind<'a> {
+    /// A run of lowercase letters and apostrophes, like `don't`
+    Word(&'a str),
+    /// A run of digits
+    Number(&'a str),
+    Open,
+    Close,
+    Comma,
+    /// Anything else, consecutive characters are merged into one token
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind<'a>,
+    /// The bytes of the input the token was read from
+    pub span: Range<usize>,
+}
+
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_lowercase() || byte == b'\''
+}
+
+/// Split `input` into tokens in a single pass. The tokens cover the whole
+/// input without gaps, so a token directly follows the one before it.
+pub fn tokenize(input: &str) -> Vec<Token<'_>> {
+    let bytes = input.as_bytes();
+    let mut tokens: Vec<Token> = Vec::new();
+
+    let mut start = 0;
+    while start < bytes.len() {
+        let run = |predicate: fn(u8) -> bool| {
+            bytes[start..]
+                .iter()
+                .position(|&byte| !predicate(byte))
+                .map_or(bytes.len(), |length| start + length)
+        };
+
+        let (kind, end) = match bytes[start] {
+            b'(' => (TokenKind::Open, start + 1),
+            b')' => (TokenKind::Close, start + 1),
+            b',' => (TokenKind::Comma, start + 1),
+            byte if is_word_byte(byte) => {
+                let end = run(is_word_byte);
+                (TokenKind::Word(&input[start..end]), end)
+            }
+            byte if byte.is_ascii_digit() => {
+                let end = run(|byte| byte.is_ascii_digit());
+                (TokenKind::Number(&input[start..end]), end)
+            }
+            _ => (TokenKind::Other, start + 1),
+        };
+
+        match tokens.last_mut() {
+            Some(last) if kind == TokenKind::Other && last.kind == TokenKind::Other => {
+                last.span.end = end;
+            }
+            _ => tokens.push(Token {
+                kind,
+                span: start..end,
+            }),
+        }
+        start = end;
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_with_spans() {
+        let kinds = |input| {
+            tokenize(input)
+                .into_iter()
+                .map(|token| (token.kind, token.span))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            kinds("xmul(12,3)!?don't"),
+            [
+                (TokenKind::Word("xmul"), 0..4),
+                (TokenKind::Open, 4..5),
+                (TokenKind::Number("12"), 5..7),
+                (TokenKind::Comma, 7..8),
+                (TokenKind::Number("3"), 8..9),
+                (TokenKind::Close, 9..10),
+                (TokenKind::Other, 10..12),
+                (TokenKind::Word("don't"), 12..17),
+            ]
+        );
+        assert_eq!(kinds(""), []);
+    }
+}
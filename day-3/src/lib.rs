@@ -0,0 +1,82 @@
+use std::{error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+use interpreter::Interpreter;
+use lexer::{tokenize, Token};
+
+pub mod generate;
+pub mod interpreter;
+pub mod lexer;
+pub mod trace;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    NonAscii { location: Location, found: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::NonAscii { found, .. } => {
+                write!(f, "expected an ASCII character, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::NonAscii { location, .. } => *location,
+        }
+    }
+}
+
+pub struct Input<'a> {
+    pub text: &'a str,
+    pub tokens: Vec<Token<'a>>,
+}
+
+// Spans are reported in bytes, which are also characters when the input is
+// ASCII
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    match input.char_indices().find(|(_, c)| !c.is_ascii()) {
+        Some((offset, found)) => Err(ParseError::NonAscii {
+            location: Location::of(input, &input[offset..]),
+            found,
+        }),
+        None => Ok(Input {
+            text: input,
+            tokens: tokenize(input),
+        }),
+    }
+}
+
+pub fn part1(input: &Input) -> Answer {
+    (Interpreter::part1().run(&input.tokens) as u64).into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    (Interpreter::part2().run(&input.tokens) as u64).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+
+        assert_eq!(part1(&parse(input).unwrap()), Answer::Number(161));
+    }
+
+    #[test]
+    fn sample_input_2() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        assert_eq!(part2(&parse(input).unwrap()), Answer::Number(48));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::interpreter::{Effect, Step};
+
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const CYAN: &str = "\x1b[36m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// A line per instruction with its byte offset, its operands, whether it
+/// ran and what it added to the sum
+pub fn listing(text: &str, steps: &[Step]) -> String {
+    let mut listing = String::new();
+    let mut sum = 0;
+
+    for step in steps {
+        let call = &step.call;
+        let operands = call
+            .operands
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let outcome = match (call.instruction.effect, step.contribution) {
+            (_, Some(contribution)) => {
+                sum += contribution;
+                format!("{contribution:+} = {sum}")
+            }
+            (Effect::Value(_), None) => String::from("skipped, disabled"),
+            (Effect::Enable | Effect::Disable, _) if step.enabled => String::from("enabled"),
+            (Effect::Enable | Effect::Disable, _) => String::from("disabled"),
+        };
+
+        listing.push_str(&format!(
+            "{:>8}  {:<16}  [{operands}]  {outcome}\n",
+            call.span.start,
+            &text[call.span.clone()]
+        ));
+    }
+
+    listing
+}
+
+/// The input with the instructions coloured in place: green when they
+/// counted, red when they were disabled and cyan for switches. Everything
+/// else is dimmed.
+pub fn highlight(text: &str, steps: &[Step]) -> String {
+    let mut highlighted = String::with_capacity(text.len() * 2);
+    let mut at = 0;
+
+    // Instructions can follow each other directly, with nothing to dim
+    let dim = |highlighted: &mut String, gap: &str| {
+        if !gap.is_empty() {
+            highlighted.push_str(&format!("{DIM}{gap}{RESET}"));
+        }
+    };
+
+    for step in steps {
+        let span = step.call.span.clone();
+        let colour = match (step.call.instruction.effect, step.contribution) {
+            (Effect::Value(_), Some(_)) => GREEN,
+            (Effect::Value(_), None) => RED,
+            (Effect::Enable | Effect::Disable, _) => CYAN,
+        };
+
+        dim(&mut highlighted, &text[at..span.start]);
+        highlighted.push_str(&format!("{colour}{}{RESET}", &text[span.clone()]));
+        at = span.end;
+    }
+    dim(&mut highlighted, &text[at..]);
+
+    highlighted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        interpreter::{Interpreter, SUB},
+        parse,
+    };
+
+    #[test]
+    fn sample_trace() {
+        let input =
+            parse("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))")
+                .unwrap();
+        let interpreter = Interpreter::part2();
+        let steps = interpreter.trace(&input.tokens);
+
+        assert_eq!(
+            listing(input.text, &steps),
+            "       1  mul(2,4)          [2, 4]  +8 = 8
+      20  don't()           []  disabled
+      28  mul(5,5)          [5, 5]  skipped, disabled
+      48  mul(11,8)         [11, 8]  skipped, disabled
+      59  do()              []  enabled
+      64  mul(8,5)          [8, 5]  +40 = 48
+"
+        );
+        assert!(highlight(input.text, &steps).starts_with(
+            "\x1b[2mx\x1b[0m\x1b[32mmul(2,4)\x1b[0m\x1b[2m&mul[3,7]!^\x1b[0m\x1b[36mdon't()\x1b[0m"
+        ));
+    }
+
+    #[test]
+    fn negative_contributions() {
+        let input = parse("mul(2,3)sub(1,5)").unwrap();
+        let interpreter = Interpreter::part2().with(SUB);
+
+        assert_eq!(
+            listing(input.text, &interpreter.trace(&input.tokens)),
+            "       0  mul(2,3)          [2, 3]  +6 = 6
+       8  sub(1,5)          [1, 5]  -4 = 2
+"
+        );
+    }
+
+    #[test]
+    fn adjacent_instructions() {
+        let input = parse("don't()mul(2,4)do()").unwrap();
+        let interpreter = Interpreter::part2();
+
+        assert_eq!(
+            highlight(input.text, &interpreter.trace(&input.tokens)),
+            "\x1b[36mdon't()\x1b[0m\x1b[31mmul(2,4)\x1b[0m\x1b[36mdo()\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use common::Rng;
+use grid::{Grid, Point};
+
+pub const DEFAULT_SIZE: usize = 140;
+
+/// A word search of `size` by `size` letters with extra XMAS words and
+/// X-MAS crosses planted on top of random letters
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    let mut grid = Grid::new(size, size, 'X');
+    for point in grid.points().collect::<Vec<_>>() {
+        grid[point] = *rng.choose(&['X', 'M', 'A', 'S']);
+    }
+
+    for _ in 0..size * size / 20 {
+        let start = random_point(&mut rng, size);
+        let direction = *rng.choose(&Point::OFFSETS_8);
+        let end = start + direction * 3;
+        if grid.contains(end) {
+            for (i, letter) in "XMAS".chars().enumerate() {
+                grid[start + direction * i as isize] = letter;
+            }
+        }
+
+        let center = random_point(&mut rng, size);
+        if grid.neighbours8(center).count() == 8 {
+            grid[center] = 'A';
+            for diagonal in [Point::new(1, 1), Point::new(1, -1)] {
+                let (m, s) = if rng.chance(0.5) {
+                    (diagonal, -diagonal)
+                } else {
+                    (-diagonal, diagonal)
+                };
+                grid[center + m] = 'M';
+                grid[center + s] = 'S';
+            }
+        }
+    }
+
+    format!("{grid}\n")
+}
+
+fn random_point(rng: &mut Rng, size: usize) -> Point {
+    Point::new(rng.index(size) as isize, rng.index(size) as isize)
+}
@@ -0,0 +1,75 @@
+use common::Answer;
+pub use grid::ParseError;
+use grid::{Grid, Image, Rgb};
+use search::WordSearch;
+use template::Template;
+
+pub mod generate;
+pub mod search;
+pub mod template;
+
+// An X of two diagonal MAS, both can be read either way
+const X_MAS: &str = "M.S/.A./M.S";
+
+pub type Input = Grid<char>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    Grid::parse(input, |c| c)
+}
+
+pub fn part1(grid: &Input) -> Answer {
+    WordSearch::new(["XMAS"]).find(grid).len().into()
+}
+
+pub fn part2(grid: &Input) -> Answer {
+    Template::parse(X_MAS).unwrap().find(grid).len().into()
+}
+
+/// The word search in shades of grey per letter, with a line through every
+/// XMAS and the letters of every X-MAS in blue
+pub fn image(grid: &Input) -> Image {
+    let mut image = Image::new(grid, |_, c| match c {
+        'X' => Rgb(60, 60, 60),
+        'M' => Rgb(100, 100, 100),
+        'A' => Rgb(140, 140, 140),
+        _ => Rgb(180, 180, 180),
+    });
+
+    for placement in Template::parse(X_MAS).unwrap().find(grid) {
+        image.highlight(placement.cells, Rgb::BLUE);
+    }
+
+    for found in WordSearch::new(["XMAS"]).find(grid) {
+        image.path(
+            vec![found.start, found.start + found.direction * 3],
+            Rgb::RED,
+        );
+    }
+
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(18));
+        assert_eq!(part2(&input), Answer::Number(9));
+    }
+
+    #[test]
+    fn sample_input_2() {
+        let input = "..X...
+.SAMX.
+.A..A.
+XMAS.S
+.X....";
+
+        assert_eq!(part1(&parse(input).unwrap()), Answer::Number(4));
+    }
+}
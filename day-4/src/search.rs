@@ -0,0 +1,178 @@
+use std::collections::{HashMap, VecDeque};
+
+use grid::{Grid, Point};
+
+/// A word found in the grid, reading from `start` in steps of `direction`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Match {
+    /// Index of the word in the dictionary
+    pub word: usize,
+    pub start: Point,
+    pub direction: Point,
+}
+
+// A node of the trie, `fail` is the node for the longest proper suffix of
+// this node's prefix that is also in the trie
+#[derive(Debug, Default)]
+struct Node {
+    next: HashMap<char, usize>,
+    fail: usize,
+    // The words ending here, including those ending at the fail nodes
+    words: Vec<usize>,
+}
+
+/// Finds all words of a dictionary at once with an Aho–Corasick automaton,
+/// which is run along every line of the grid in all 8 directions
+#[derive(Debug)]
+pub struct WordSearch {
+    lengths: Vec<usize>,
+    nodes: Vec<Node>,
+}
+
+impl WordSearch {
+    /// A search for `words`, which are numbered in order for `Match::word`.
+    /// Empty words keep their number but are never found.
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> WordSearch {
+        let mut nodes = vec![Node::default()];
+        let mut lengths = Vec::new();
+
+        for (i, word) in words.into_iter().enumerate() {
+            lengths.push(word.as_ref().chars().count());
+            if word.as_ref().is_empty() {
+                continue;
+            }
+
+            let mut node = 0;
+            for c in word.as_ref().chars() {
+                node = match nodes[node].next.get(&c) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[node].next.insert(c, next);
+                        next
+                    }
+                };
+            }
+            nodes[node].words.push(i);
+        }
+
+        // Fail links in breadth first order, so the fail node of a parent
+        // is always done before its children
+        let mut queue = VecDeque::from_iter(nodes[0].next.values().copied());
+        while let Some(node) = queue.pop_front() {
+            let children = nodes[node]
+                .next
+                .iter()
+                .map(|(&c, &child)| (c, child))
+                .collect::<Vec<_>>();
+            for (c, child) in children {
+                let mut fail = nodes[node].fail;
+                let fail = loop {
+                    match nodes[fail].next.get(&c) {
+                        Some(&next) => break next,
+                        None if fail == 0 => break 0,
+                        None => fail = nodes[fail].fail,
+                    }
+                };
+                nodes[child].fail = fail;
+                let inherited = nodes[fail].words.clone();
+                nodes[child].words.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        WordSearch { lengths, nodes }
+    }
+
+    fn step(&self, mut node: usize, c: char) -> usize {
+        loop {
+            if let Some(&next) = self.nodes[node].next.get(&c) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    /// Every occurrence of every word, words that read the same backwards
+    /// are found once in each direction
+    pub fn find(&self, grid: &Grid<char>) -> Vec<Match> {
+        let mut matches = Vec::new();
+
+        for direction in Point::OFFSETS_8 {
+            // Every line starts at a point that has no point before it
+            let starts = grid
+                .points()
+                .filter(|&point| !grid.contains(point - direction));
+            for start in starts {
+                let mut node = 0;
+                let mut point = start;
+                while let Some(&c) = grid.get(point) {
+                    node = self.step(node, c);
+                    for &word in &self.nodes[node].words {
+                        let length = self.lengths[word] as isize;
+                        matches.push(Match {
+                            word,
+                            start: point - direction * (length - 1),
+                            direction,
+                        });
+                    }
+                    point += direction;
+                }
+            }
+        }
+
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping_words() {
+        let grid = Grid::parse("XMASAMX\n.......", |c| c).unwrap();
+        let matches = WordSearch::new(["XMAS", "MAS", "SAMX", "A"]).find(&grid);
+
+        let (east, west) = (Point::new(1, 0), Point::new(-1, 0));
+        let mut words = matches
+            .iter()
+            .filter(|found| found.word != 3)
+            .map(|found| (found.word, found.start, found.direction))
+            .collect::<Vec<_>>();
+        words.sort();
+        assert_eq!(
+            words,
+            [
+                (0, Point::new(0, 0), east),
+                (0, Point::new(6, 0), west),
+                (1, Point::new(1, 0), east),
+                (1, Point::new(5, 0), west),
+                (2, Point::new(3, 0), west),
+                (2, Point::new(3, 0), east),
+            ]
+        );
+        // A single letter reads the same in every direction
+        assert_eq!(matches.iter().filter(|found| found.word == 3).count(), 16);
+    }
+
+    #[test]
+    fn empty_words() {
+        let grid = Grid::parse("XMAS", |c| c).unwrap();
+        let matches = WordSearch::new(["", "XMAS", ""]).find(&grid);
+
+        assert_eq!(
+            matches,
+            [Match {
+                word: 1,
+                start: Point::new(0, 0),
+                direction: Point::new(1, 0),
+            }]
+        );
+        assert_eq!(WordSearch::new([""]).find(&grid), []);
+    }
+}
@@ -0,0 +1,131 @@
+use grid::{Grid, ParseError, Point};
+
+/// A small grid of letters to look for, where `.` matches any letter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    cells: Grid<Option<char>>,
+}
+
+/// Where a template was found: the top left corner of one of its variants
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub position: Point,
+    /// Index into `Template::variants`
+    pub variant: usize,
+    /// The cells of the grid matched by letters of the template
+    pub cells: Vec<Point>,
+}
+
+impl Template {
+    /// Parse a template with its rows separated by `/` or newlines, like
+    /// `M.S/.A./M.S`
+    pub fn parse(pattern: &str) -> Result<Template, ParseError> {
+        let cells = Grid::parse(&pattern.replace('/', "\n"), |c| (c != '.').then_some(c))?;
+
+        Ok(Template { cells })
+    }
+
+    fn rotate(&self) -> Template {
+        let (width, height) = (self.cells.width(), self.cells.height());
+        let mut cells = Grid::new(height, width, None);
+        for (point, &cell) in self.cells.iter() {
+            cells[Point::new(height as isize - 1 - point.y, point.x)] = cell;
+        }
+
+        Template { cells }
+    }
+
+    fn reflect(&self) -> Template {
+        let width = self.cells.width() as isize;
+        let mut cells = self.cells.clone();
+        for (point, &cell) in self.cells.iter() {
+            cells[Point::new(width - 1 - point.x, point.y)] = cell;
+        }
+
+        Template { cells }
+    }
+
+    /// All distinct rotations and reflections of the template, starting
+    /// with the template itself
+    pub fn variants(&self) -> Vec<Template> {
+        let mut variants: Vec<Template> = Vec::new();
+
+        for start in [self.clone(), self.reflect()] {
+            let mut variant = start;
+            for _ in 0..4 {
+                let next = variant.rotate();
+                if !variants.contains(&variant) {
+                    variants.push(variant);
+                }
+                variant = next;
+            }
+        }
+
+        variants
+    }
+
+    fn matches_at(&self, grid: &Grid<char>, position: Point) -> bool {
+        self.cells.iter().all(|(point, cell)| {
+            grid.get(position + point)
+                .is_some_and(|c| cell.is_none_or(|cell| cell == *c))
+        })
+    }
+
+    /// Every placement of every variant of the template in `grid`
+    pub fn find(&self, grid: &Grid<char>) -> Vec<Placement> {
+        let mut placements = Vec::new();
+
+        for (i, variant) in self.variants().iter().enumerate() {
+            for position in grid.points() {
+                if variant.matches_at(grid, position) {
+                    placements.push(Placement {
+                        position,
+                        variant: i,
+                        cells: variant
+                            .cells
+                            .iter()
+                            .filter(|(_, cell)| cell.is_some())
+                            .map(|(point, _)| position + point)
+                            .collect(),
+                    });
+                }
+            }
+        }
+
+        placements
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variants() {
+        let cross = Template::parse("M.S/.A./M.S").unwrap();
+        let corner = Template::parse("XM/.A").unwrap();
+        let line = Template::parse("XMAS").unwrap();
+
+        assert_eq!(cross.variants().len(), 4);
+        assert_eq!(corner.variants().len(), 8);
+        assert_eq!(line.variants().len(), 4);
+        assert_eq!(line.variants()[1], Template::parse("X/M/A/S").unwrap());
+    }
+
+    #[test]
+    fn placements() {
+        let grid = Grid::parse("XMX\nMAM\nXMX", |c| c).unwrap();
+        let placements = Template::parse("XM/.A").unwrap().find(&grid);
+
+        // Every corner has two letters M next to it
+        assert_eq!(placements.len(), 8);
+        assert_eq!(
+            placements[0],
+            Placement {
+                position: Point::new(0, 0),
+                variant: 0,
+                cells: vec![Point::new(0, 0), Point::new(1, 0), Point::new(1, 1)],
+            }
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    check,
+    order::{topological_order, OrderError, TieBreak},
+    Input,
+};
+
+/// A rule `before|after` that an update breaks, as `after` comes first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub before: u32,
+    pub after: u32,
+    pub before_position: usize,
+    pub after_position: usize,
+}
+
+/// Every rule broken by `update`, ordered by the position of `after`
+pub fn violations(update: &[u32], page_order_rules: &HashMap<u32, HashSet<u32>>) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    for (after_position, &after) in update.iter().enumerate() {
+        for (before_position, &before) in update.iter().enumerate().skip(after_position + 1) {
+            if page_order_rules
+                .get(&before)
+                .is_some_and(|successors| successors.contains(&after))
+            {
+                violations.push(Violation {
+                    before,
+                    after,
+                    before_position,
+                    after_position,
+                });
+            }
+        }
+    }
+
+    violations
+}
+
+/// What it takes to put an update in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Audit {
+    pub violations: Vec<Violation>,
+    /// The pairs of pages that are the wrong way round compared to the
+    /// order from `TieBreak::Original`, which is how many swaps of
+    /// neighbouring pages it takes to fix it
+    pub inversions: usize,
+    /// The fewest pages to take out and put back elsewhere to turn the
+    /// update into the order from `TieBreak::Original`, all others are
+    /// already in that order. When the rules allow other orders, one of
+    /// those may take fewer moves.
+    pub moves: Vec<u32>,
+}
+
+/// Audit `update` against the order found by a topological sort, where
+/// pages the rules don't order keep their order from the update
+pub fn audit(
+    update: &[u32],
+    page_order_rules: &HashMap<u32, HashSet<u32>>,
+) -> Result<Audit, OrderError> {
+    let order = topological_order(update, page_order_rules, TieBreak::Original)?;
+    let rank = order
+        .iter()
+        .enumerate()
+        .map(|(i, &page)| (page, i))
+        .collect::<HashMap<_, _>>();
+    let ranks = update.iter().map(|page| rank[page]).collect::<Vec<_>>();
+
+    let inversions = (0..ranks.len())
+        .map(|i| {
+            ranks[i + 1..]
+                .iter()
+                .filter(|&&rank| rank < ranks[i])
+                .count()
+        })
+        .sum();
+
+    // The pages of the longest increasing run of ranks can stay put
+    let mut length = vec![1; ranks.len()];
+    let mut previous = vec![None; ranks.len()];
+    for i in 0..ranks.len() {
+        for j in 0..i {
+            if ranks[j] < ranks[i] && length[j] + 1 > length[i] {
+                length[i] = length[j] + 1;
+                previous[i] = Some(j);
+            }
+        }
+    }
+    let mut stay = vec![false; ranks.len()];
+    let mut at = (0..ranks.len()).max_by_key(|&i| (length[i], usize::MAX - i));
+    while let Some(i) = at {
+        stay[i] = true;
+        at = previous[i];
+    }
+
+    Ok(Audit {
+        violations: violations(update, page_order_rules),
+        inversions,
+        moves: update
+            .iter()
+            .zip(stay)
+            .filter(|(_, stay)| !stay)
+            .map(|(&page, _)| page)
+            .collect(),
+    })
+}
+
+/// The audit of every update that breaks a rule, with the update counted
+/// from 1 and its pages on the first line and the broken rules, inversions
+/// and moves on the lines below it
+pub fn report(input: &Input) -> String {
+    let mut report = String::new();
+
+    for (i, update) in input.updates.iter().enumerate() {
+        if check(update, &input.page_order_rules) {
+            continue;
+        }
+        let pages = update.iter().map(u32::to_string).collect::<Vec<_>>();
+        report.push_str(&format!("update {}: {}\n", i + 1, pages.join(",")));
+
+        match audit(update, &input.page_order_rules) {
+            Ok(audit) => {
+                let rules = audit
+                    .violations
+                    .iter()
+                    .map(|violation| format!("{}|{}", violation.before, violation.after))
+                    .collect::<Vec<_>>();
+                let moves = audit.moves.iter().map(u32::to_string).collect::<Vec<_>>();
+                report.push_str(&format!("  breaks: {}\n", rules.join(" ")));
+                report.push_str(&format!("  inversions: {}\n", audit.inversions));
+                report.push_str(&format!("  moves: {}\n", moves.join(",")));
+            }
+            Err(err) => report.push_str(&format!("  cannot be fixed: {err}\n")),
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn sample_audits() {
+        let input = parse(
+            "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n61|53\n\
+             97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13\n\n\
+             75,97,47,61,53\n97,13,75,29,47",
+        )
+        .unwrap();
+
+        let first = audit(&input.updates[0], &input.page_order_rules).unwrap();
+        assert_eq!(
+            first,
+            Audit {
+                violations: vec![Violation {
+                    before: 97,
+                    after: 75,
+                    before_position: 1,
+                    after_position: 0,
+                }],
+                inversions: 1,
+                moves: vec![97],
+            }
+        );
+
+        // 97,75,47,29,13 once fixed
+        let second = audit(&input.updates[1], &input.page_order_rules).unwrap();
+        assert_eq!(second.inversions, 4);
+        assert_eq!(second.violations.len(), 4);
+        assert_eq!(second.moves, [13, 47]);
+    }
+
+    #[test]
+    fn rejected_updates_report() {
+        let input = parse("1|2\n2|3\n3|1\n4|5\n\n4,5\n5,4\n3,2,1").unwrap();
+
+        assert_eq!(
+            report(&input),
+            "update 2: 5,4
+  breaks: 4|5
+  inversions: 1
+  moves: 4
+update 3: 3,2,1
+  cannot be fixed: the rules for pages 1, 2, 3 form a cycle
+"
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 200;
+
+/// Ordering rules for every pair of 49 pages followed by `size` updates of
+/// an odd number of pages, about half of which are in the right order
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    // The position of a page in this list is its place in the total order
+    let mut pages = (10..100).collect::<Vec<u32>>();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules = pages
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| pages[i + 1..].iter().map(move |b| format!("{a}|{b}\n")))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut rules);
+
+    let updates = (0..size)
+        .map(|_| {
+            let length = 2 * rng.between(2, 11) as usize + 1;
+            let mut indices = (0..pages.len()).collect::<Vec<_>>();
+            rng.shuffle(&mut indices);
+            indices.truncate(length);
+            if rng.chance(0.5) {
+                indices.sort();
+            }
+
+            let update = indices
+                .iter()
+                .map(|i| pages[*i].to_string())
+                .collect::<Vec<_>>();
+            format!("{}\n", update.join(","))
+        })
+        .collect::<String>();
+
+    format!("{}\n{updates}", rules.concat())
+}
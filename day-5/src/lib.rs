@@ -0,0 +1,205 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+};
+
+use common::{Answer, InputError, Location};
+use order::{topological_order, OrderError, TieBreak};
+
+pub mod audit;
+pub mod generate;
+pub mod order;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    MissingUpdates { location: Location },
+    ExpectedRule { location: Location, found: String },
+    ExpectedNumber { location: Location, found: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingUpdates { .. } => {
+                write!(f, "expected an empty line followed by the updates")
+            }
+            ParseError::ExpectedRule { found, .. } => {
+                write!(f, "expected a rule like 47|53, found {found:?}")
+            }
+            ParseError::ExpectedNumber { found, .. } => {
+                write!(f, "expected a page number, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::MissingUpdates { location }
+            | ParseError::ExpectedRule { location, .. }
+            | ParseError::ExpectedNumber { location, .. } => *location,
+        }
+    }
+}
+
+fn check(update: &[u32], page_order_rules: &HashMap<u32, HashSet<u32>>) -> bool {
+    let mut prev_pages = HashSet::<u32>::new();
+
+    for page in update.iter() {
+        if let Some(order_rules) = page_order_rules.get(page) {
+            if prev_pages.intersection(order_rules).next().is_some() {
+                return false;
+            }
+        }
+        prev_pages.insert(*page);
+    }
+
+    true
+}
+
+pub struct Input {
+    page_order_rules: HashMap<u32, HashSet<u32>>,
+    updates: Vec<Vec<u32>>,
+}
+
+fn parse_page(input: &str, page: &str) -> Result<u32, ParseError> {
+    page.parse::<u32>().map_err(|_| ParseError::ExpectedNumber {
+        location: Location::of(input, page),
+        found: String::from(page),
+    })
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let (page_order_rules_input, updates_input) =
+        input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::MissingUpdates {
+                location: Location::end_of(input),
+            })?;
+
+    let mut page_order_rules = HashMap::<u32, HashSet<u32>>::new();
+    for line in page_order_rules_input.lines() {
+        let (a, b) = line
+            .split_once('|')
+            .ok_or_else(|| ParseError::ExpectedRule {
+                location: Location::of(input, line),
+                found: String::from(line),
+            })?;
+        page_order_rules
+            .entry(parse_page(input, a)?)
+            .or_default()
+            .insert(parse_page(input, b)?);
+    }
+
+    let updates = updates_input
+        .lines()
+        .map(|line| {
+            line.split(',')
+                .map(|n| parse_page(input, n))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Input {
+        page_order_rules,
+        updates,
+    })
+}
+
+pub fn part1(input: &Input) -> Answer {
+    let valid_update_middle_page_number_sum: u32 = input
+        .updates
+        .iter()
+        .filter(|update| check(update, &input.page_order_rules))
+        .map(|update| update[update.len() / 2])
+        .sum();
+
+    valid_update_middle_page_number_sum.into()
+}
+
+/// An update whose pages can't be put in order, `update` counts from 0
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateError {
+    pub update: usize,
+    pub error: OrderError,
+}
+
+impl fmt::Display for UpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "update {} cannot be fixed: {}",
+            self.update + 1,
+            self.error
+        )
+    }
+}
+
+impl Error for UpdateError {}
+
+/// Every update that breaks a rule, with its pages put in order
+pub fn fix_updates(input: &Input, tie_break: TieBreak) -> Result<Vec<Vec<u32>>, UpdateError> {
+    input
+        .updates
+        .iter()
+        .enumerate()
+        .filter(|(_, update)| !check(update, &input.page_order_rules))
+        .map(|(i, update)| {
+            topological_order(update, &input.page_order_rules, tie_break)
+                .map_err(|error| UpdateError { update: i, error })
+        })
+        .collect()
+}
+
+// Only a cycle in the rules can stop an update from being fixed, and then
+// there is no middle page to add
+pub fn part2(input: &Input) -> Result<Answer, UpdateError> {
+    Ok(fix_updates(input, TieBreak::Original)?
+        .iter()
+        .map(|update| update[update.len() / 2])
+        .sum::<u32>()
+        .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(143));
+        assert_eq!(part2(&input), Ok(Answer::Number(123)));
+        assert_eq!(
+            fix_updates(&input, TieBreak::Strict),
+            Ok(vec![
+                vec![97, 75, 47, 61, 53],
+                vec![61, 29, 13],
+                vec![97, 75, 47, 29, 13],
+            ])
+        );
+    }
+
+    #[test]
+    fn unfixable_updates() {
+        let input = parse("1|2\n2|3\n3|1\n4|5\n\n4,5\n5,4\n3,2,1").unwrap();
+
+        let unfixable = UpdateError {
+            update: 2,
+            error: OrderError::Cycle {
+                pages: vec![1, 2, 3],
+            },
+        };
+        assert_eq!(
+            fix_updates(&input, TieBreak::Original),
+            Err(unfixable.clone())
+        );
+        assert_eq!(part2(&input), Err(unfixable));
+    }
+}
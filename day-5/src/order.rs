@@ -0,0 +1,201 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+};
+
+/// Which page goes first when the rules allow more than one page next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Report the choice as an error, so only updates with a single valid
+    /// order can be sorted
+    Strict,
+    /// The page that came first in the update
+    Original,
+    Smallest,
+    Largest,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderError {
+    /// The rules between these pages go round in a circle, so none of them
+    /// can be placed first
+    Cycle { pages: Vec<u32> },
+    /// Any of these pages could be placed at `position`
+    Ambiguous { position: usize, pages: Vec<u32> },
+}
+
+impl fmt::Display for OrderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |pages: &[u32]| {
+            pages
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        match self {
+            OrderError::Cycle { pages } => {
+                write!(f, "the rules for pages {} form a cycle", list(pages))
+            }
+            OrderError::Ambiguous { position, pages } => {
+                write!(f, "pages {} can all be at position {position}", list(pages))
+            }
+        }
+    }
+}
+
+impl Error for OrderError {}
+
+/// Order the pages of `update` with Kahn's algorithm, using only the rules
+/// between pages in the update
+pub fn topological_order(
+    update: &[u32],
+    page_order_rules: &HashMap<u32, HashSet<u32>>,
+    tie_break: TieBreak,
+) -> Result<Vec<u32>, OrderError> {
+    let pages = update.iter().copied().collect::<HashSet<_>>();
+    let successors = |page: u32| {
+        page_order_rules
+            .get(&page)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|successor| pages.contains(successor))
+    };
+
+    let mut predecessors = update
+        .iter()
+        .map(|&page| (page, 0))
+        .collect::<HashMap<_, _>>();
+    for &page in &pages {
+        for successor in successors(page) {
+            *predecessors.get_mut(&successor).unwrap() += 1;
+        }
+    }
+
+    let position = update
+        .iter()
+        .enumerate()
+        .map(|(i, &page)| (page, i))
+        .collect::<HashMap<_, _>>();
+    let mut ready = update
+        .iter()
+        .copied()
+        .filter(|page| predecessors[page] == 0)
+        .collect::<Vec<_>>();
+    let mut order = Vec::with_capacity(update.len());
+
+    while !ready.is_empty() {
+        let next = match tie_break {
+            _ if ready.len() == 1 => 0,
+            TieBreak::Strict => {
+                let mut pages = ready;
+                pages.sort_unstable();
+                return Err(OrderError::Ambiguous {
+                    position: order.len(),
+                    pages,
+                });
+            }
+            TieBreak::Original => (0..ready.len())
+                .min_by_key(|&i| position[&ready[i]])
+                .unwrap(),
+            TieBreak::Smallest => (0..ready.len()).min_by_key(|&i| ready[i]).unwrap(),
+            TieBreak::Largest => (0..ready.len()).max_by_key(|&i| ready[i]).unwrap(),
+        };
+        let page = ready.swap_remove(next);
+        order.push(page);
+
+        for successor in successors(page) {
+            let count = predecessors.get_mut(&successor).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                ready.push(successor);
+            }
+        }
+    }
+
+    if order.len() < pages.len() {
+        // Pages after a cycle can't be placed either, only report the pages
+        // that can reach themselves
+        let left = pages
+            .iter()
+            .copied()
+            .filter(|page| !order.contains(page))
+            .collect::<HashSet<_>>();
+        let mut pages = left
+            .iter()
+            .copied()
+            .filter(|&page| {
+                let mut seen = HashSet::new();
+                let mut stack = successors(page).collect::<Vec<_>>();
+                while let Some(next) = stack.pop() {
+                    if next == page {
+                        return true;
+                    }
+                    if left.contains(&next) && seen.insert(next) {
+                        stack.extend(successors(next));
+                    }
+                }
+                false
+            })
+            .collect::<Vec<_>>();
+        pages.sort_unstable();
+        return Err(OrderError::Cycle { pages });
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(rules: &[(u32, u32)]) -> HashMap<u32, HashSet<u32>> {
+        let mut map = HashMap::<u32, HashSet<u32>>::new();
+        for &(a, b) in rules {
+            map.entry(a).or_default().insert(b);
+        }
+        map
+    }
+
+    #[test]
+    fn orders_and_errors() {
+        let total = rules(&[(1, 2), (2, 3), (1, 3)]);
+        assert_eq!(
+            topological_order(&[3, 1, 2], &total, TieBreak::Strict),
+            Ok(vec![1, 2, 3])
+        );
+
+        let partial = rules(&[(5, 1), (4, 1)]);
+        assert_eq!(
+            topological_order(&[1, 5, 4], &partial, TieBreak::Strict),
+            Err(OrderError::Ambiguous {
+                position: 0,
+                pages: vec![4, 5],
+            })
+        );
+        assert_eq!(
+            topological_order(&[1, 5, 4], &partial, TieBreak::Original),
+            Ok(vec![5, 4, 1])
+        );
+        assert_eq!(
+            topological_order(&[1, 5, 4], &partial, TieBreak::Smallest),
+            Ok(vec![4, 5, 1])
+        );
+        assert_eq!(
+            topological_order(&[1, 5, 4], &partial, TieBreak::Largest),
+            Ok(vec![5, 4, 1])
+        );
+
+        // 4 comes before the cycle and 5 after it
+        let cycle = rules(&[(1, 2), (2, 3), (3, 1), (4, 1), (3, 5)]);
+        assert_eq!(
+            topological_order(&[3, 4, 5, 2, 1], &cycle, TieBreak::Smallest),
+            Err(OrderError::Cycle {
+                pages: vec![1, 2, 3],
+            })
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use common::Rng;
+use grid::Grid;
+
+use crate::{parse, patrols, Outcome};
+
+pub const DEFAULT_SIZE: usize = 130;
+
+/// A `size` by `size` lab with a few percent obstructions, retried until the
+/// guard walks off the map without adding any
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    loop {
+        let mut grid = Grid::new(size, size, '.');
+        for point in grid.points().collect::<Vec<_>>() {
+            if rng.chance(0.05) {
+                grid[point] = '#';
+            }
+        }
+
+        // The guard would turn around forever when boxed in by an extra obstruction
+        let start = *rng.choose(&grid.points().collect::<Vec<_>>());
+        for neighbour in grid.neighbours4(start).collect::<Vec<_>>() {
+            grid[neighbour] = '.';
+        }
+        grid[start] = '^';
+
+        let text = format!("{grid}\n");
+        let input = parse(&text).expect("generated labs are valid");
+        if patrols(&input)
+            .iter()
+            .all(|patrol| patrol.outcome == Outcome::Leaves)
+        {
+            return text;
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use grid::{Direction, Grid, Point};
+
+/// For every free position and direction the position where the guard
+/// stops in front of an obstruction, or `None` when they walk off the map.
+/// With it the guard moves a whole straight line at once.
+pub struct JumpTable {
+    stops: Grid<[Option<Point>; 4]>,
+    // Which turns were made during which obstruction test, so the grid
+    // doesn't have to be cleared for every test
+    seen: Grid<[usize; 4]>,
+    test: usize,
+}
+
+impl JumpTable {
+    pub fn new(grid: &Grid<char>) -> JumpTable {
+        let mut stops = grid.map(|_| [None; 4]);
+
+        for direction in Direction::ALL {
+            let step = direction.offset();
+            // The positions furthest along the direction go first, so the
+            // stop of the next position is known already
+            let mut points = grid.points().collect::<Vec<_>>();
+            points.sort_by_key(|point| -(point.x * step.x + point.y * step.y));
+
+            for point in points {
+                let next = point + step;
+                stops[point][direction as usize] = match grid.get(next) {
+                    None => None,
+                    Some('#') => Some(point),
+                    Some(_) => stops[next][direction as usize],
+                };
+            }
+        }
+
+        JumpTable {
+            seen: grid.map(|_| [0; 4]),
+            stops,
+            test: 0,
+        }
+    }
+
+    // Where the guard stops when walking from `position`, taking the extra
+    // `obstruction` into account
+    fn stop(&self, position: Point, direction: Direction, obstruction: Point) -> Option<Point> {
+        let stop = self.stops[position][direction as usize];
+
+        let step = direction.offset();
+        let to_obstruction = obstruction - position;
+        let distance = to_obstruction.x * step.x + to_obstruction.y * step.y;
+        let ahead = distance > 0 && to_obstruction == step * distance;
+        let before_stop =
+            stop.is_none_or(|stop| stop.manhattan_distance(position) >= distance as usize);
+
+        if ahead && before_stop {
+            Some(obstruction - step)
+        } else {
+            stop
+        }
+    }
+
+    /// Whether a guard at `position` facing `direction` ends up in a loop
+    /// when an obstruction is added at `obstruction`
+    pub fn loops(
+        &mut self,
+        mut position: Point,
+        mut direction: Direction,
+        obstruction: Point,
+    ) -> bool {
+        self.test += 1;
+
+        loop {
+            match self.stop(position, direction, obstruction) {
+                None => return false,
+                Some(stop) => position = stop,
+            }
+
+            let seen = &mut self.seen[position][direction as usize];
+            if *seen == self.test {
+                return true;
+            }
+            *seen = self.test;
+            direction = direction.turn_right();
+        }
+    }
+}
@@ -0,0 +1,423 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+};
+
+use common::{Answer, InputError, Location};
+use grid::{Direction, Grid, Image, Point, Rgb};
+use jump::JumpTable;
+
+pub mod generate;
+pub mod jump;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Grid(grid::ParseError),
+    MissingGuard { location: Location },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Grid(err) => write!(f, "{err}"),
+            ParseError::MissingGuard { .. } => {
+                write!(f, "expected a guard '^', '>', 'v' or '<' in the grid")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::Grid(err) => err.location(),
+            ParseError::MissingGuard { location } => *location,
+        }
+    }
+}
+
+impl From<grid::ParseError> for ParseError {
+    fn from(err: grid::ParseError) -> Self {
+        ParseError::Grid(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Leaves,
+    /// The guard walks in a loop of `cycle_length` steps forward
+    Loops {
+        cycle_length: usize,
+    },
+}
+
+/// Where a guard went
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Patrol {
+    pub start: Point,
+    pub direction: Direction,
+    /// The number of distinct positions visited, including the start
+    pub visited: usize,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Patrol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "guard at {} facing {:?}: visits {} positions and ",
+            self.start, self.direction, self.visited
+        )?;
+        match self.outcome {
+            Outcome::Leaves => write!(f, "leaves"),
+            Outcome::Loops { cycle_length } => write!(f, "loops every {cycle_length} steps"),
+        }
+    }
+}
+
+// The position and direction of the guard at every step until they leave
+// the map, or until they would start walking in a loop. Turning is a step
+// of its own. For a loop the index of the first state of the loop is
+// returned as well.
+fn walk(
+    grid: &Grid<char>,
+    start: Point,
+    direction: Direction,
+) -> (Vec<(Point, Direction)>, Option<usize>) {
+    let mut position = start;
+    let mut dir = direction;
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+
+    loop {
+        if let Some(&loop_start) = seen.get(&(position, dir)) {
+            return (states, Some(loop_start));
+        }
+        seen.insert((position, dir), states.len());
+        states.push((position, dir));
+
+        let new_position = position.step(dir);
+        match grid.get(new_position) {
+            None => return (states, None),
+            Some(&'#') => dir = dir.turn_right(),
+            Some(_) => position = new_position,
+        }
+    }
+}
+
+fn patrol(grid: &Grid<char>, start: Point, direction: Direction) -> Patrol {
+    let (states, loop_start) = walk(grid, start, direction);
+
+    let visited = states
+        .iter()
+        .map(|(position, _)| position)
+        .collect::<HashSet<_>>()
+        .len();
+    let outcome = match loop_start {
+        None => Outcome::Leaves,
+        Some(loop_start) => {
+            let cycle = &states[loop_start..];
+            let turns = cycle
+                .iter()
+                .zip(cycle.iter().cycle().skip(1))
+                .filter(|((a, _), (b, _))| a == b)
+                .count();
+            Outcome::Loops {
+                cycle_length: cycle.len() - turns,
+            }
+        }
+    };
+
+    Patrol {
+        start,
+        direction,
+        visited,
+        outcome,
+    }
+}
+
+pub struct Input {
+    grid: Grid<char>,
+    guards: Vec<(Point, Direction)>,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let grid = Grid::try_parse(input, "'.', '#' or a guard like '^'", |c| {
+        ['.', '#', '^', '>', 'v', '<'].contains(&c).then_some(c)
+    })?;
+    let guards = grid
+        .iter()
+        .filter_map(|(point, &c)| Some((point, Direction::from_arrow(c)?)))
+        .collect::<Vec<_>>();
+    if guards.is_empty() {
+        return Err(ParseError::MissingGuard {
+            location: Location::end_of(input),
+        });
+    }
+
+    Ok(Input { grid, guards })
+}
+
+/// The patrol of every guard, each walking as if the others aren't there
+pub fn patrols(input: &Input) -> Vec<Patrol> {
+    input
+        .guards
+        .iter()
+        .map(|&(start, direction)| patrol(&input.grid, start, direction))
+        .collect()
+}
+
+// The positions visited by any of the guards
+pub fn part1(input: &Input) -> Answer {
+    input
+        .guards
+        .iter()
+        .flat_map(|&(start, direction)| walk(&input.grid, start, direction).0)
+        .map(|(position, _)| position)
+        .collect::<HashSet<_>>()
+        .len()
+        .into()
+}
+
+// Every free position where an extra obstruction makes a guard that
+// would otherwise leave walk in a loop. Only positions on the route
+// matter, and the guard is only followed from where they would first walk
+// into the obstruction.
+fn loop_obstructions(input: &Input) -> Vec<Point> {
+    let mut jumps = JumpTable::new(&input.grid);
+    let mut obstructions = HashSet::new();
+
+    for &(start, direction) in &input.guards {
+        let (states, loop_start) = walk(&input.grid, start, direction);
+        if loop_start.is_some() {
+            continue;
+        }
+
+        let mut tested = input.grid.map(|c| Direction::from_arrow(*c).is_some());
+        for (position, direction) in states {
+            let ahead = position.step(direction);
+            if matches!(input.grid.get(ahead), None | Some('#')) || tested[ahead] {
+                continue;
+            }
+            tested[ahead] = true;
+
+            if jumps.loops(position, direction, ahead) {
+                obstructions.insert(ahead);
+            }
+        }
+    }
+
+    let mut obstructions = obstructions.into_iter().collect::<Vec<_>>();
+    obstructions.sort();
+    obstructions
+}
+
+pub fn part2(input: &Input) -> Answer {
+    loop_obstructions(input).len().into()
+}
+
+// The positions of the guard in order
+fn route(grid: &Grid<char>, start: Point, direction: Direction) -> Vec<Point> {
+    let mut route = walk(grid, start, direction)
+        .0
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect::<Vec<_>>();
+    route.dedup();
+
+    route
+}
+
+/// The lab with the route of the guard drawn over the positions they
+/// visit, and the positions where an obstruction makes them loop in red
+pub fn image(input: &Input) -> Image {
+    let mut image = Image::new(&input.grid, |_, c| match c {
+        '#' => Rgb::BLACK,
+        _ => Rgb::WHITE,
+    });
+
+    let routes = input
+        .guards
+        .iter()
+        .map(|&(start, direction)| route(&input.grid, start, direction))
+        .collect::<Vec<_>>();
+    for route in &routes {
+        image.highlight(route.iter().copied(), Rgb(200, 220, 255));
+    }
+    image.highlight(loop_obstructions(input), Rgb::RED);
+    image.highlight(input.guards.iter().map(|(start, _)| *start), Rgb::GREEN);
+    for route in routes {
+        image.path(route, Rgb::BLUE);
+    }
+
+    image
+}
+
+/// The lab as text with the patrol of every guard drawn like in the puzzle
+/// text: `|` and `-` where they walk up and down or sideways, and `+` where
+/// they turn or cross their path. Every position where an obstruction makes
+/// a guard loop is marked with `O`.
+pub fn render_patrols(input: &Input) -> String {
+    // Whether a position is walked through vertically and horizontally
+    let mut walked = input.grid.map(|_| (false, false));
+    for &(start, direction) in &input.guards {
+        let (states, _) = walk(&input.grid, start, direction);
+        for window in states.windows(2) {
+            let ((from, direction), (to, _)) = (window[0], window[1]);
+            for position in [from, to] {
+                let cell = &mut walked[position];
+                if from == to {
+                    *cell = (true, true);
+                } else if direction.is_horizontal() {
+                    cell.1 = true;
+                } else {
+                    cell.0 = true;
+                }
+            }
+        }
+    }
+
+    let obstructions = loop_obstructions(input).into_iter().collect::<HashSet<_>>();
+    input.grid.render(|point, &c| {
+        if obstructions.contains(&point) {
+            return 'O';
+        }
+        match (c, walked[point]) {
+            ('.', (true, true)) => '+',
+            ('.', (true, false)) => '|',
+            ('.', (false, true)) => '-',
+            _ => c,
+        }
+    })
+}
+
+/// Every step of every guard as CSV, turning counts as a step
+pub fn states_csv(input: &Input) -> String {
+    let mut csv = String::from("guard,step,x,y,direction\n");
+
+    for (guard, &(start, direction)) in input.guards.iter().enumerate() {
+        let (states, _) = walk(&input.grid, start, direction);
+        for (step, (position, direction)) in states.into_iter().enumerate() {
+            csv.push_str(&format!(
+                "{guard},{step},{},{},{direction:?}\n",
+                position.x, position.y
+            ));
+        }
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(41));
+        assert_eq!(part2(&input), Answer::Number(6));
+    }
+
+    #[test]
+    fn jump_table_matches_brute_force() {
+        let input = generate::generate(3, 30);
+        let input = parse(&input).unwrap();
+
+        let mut grid = input.grid.clone();
+        let mut brute_force = grid
+            .positions(|c| *c == '.')
+            .collect::<Vec<_>>()
+            .into_iter()
+            .filter(|&position| {
+                grid[position] = '#';
+                let (start, direction) = input.guards[0];
+                let loops = patrol(&grid, start, direction).outcome != Outcome::Leaves;
+                grid[position] = '.';
+                loops
+            })
+            .collect::<Vec<_>>();
+        let mut obstructions = loop_obstructions(&input);
+
+        brute_force.sort();
+        obstructions.sort();
+        assert_eq!(obstructions, brute_force);
+    }
+
+    #[test]
+    fn sample_patrol_exports() {
+        let input = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+        let input = parse(input).unwrap();
+
+        assert_eq!(
+            render_patrols(&input),
+            "....#.....
+....+---+#
+....|...|.
+..#.|...|.
+..+-+-+#|.
+..|.|.|.|.
+.#+O^-+-+.
+.+----OO#.
+#O-O--+|..
+......#O.."
+        );
+        assert!(
+            states_csv(&input).starts_with("guard,step,x,y,direction\n0,0,4,6,Up\n0,1,4,5,Up\n")
+        );
+        assert!(states_csv(&input).ends_with("0,53,7,8,Down\n0,54,7,9,Down\n"));
+    }
+
+    #[test]
+    fn several_guards() {
+        let input = ".#...
+.^..#
+..v..
+#....
+...#.";
+        let input = parse(input).unwrap();
+
+        assert_eq!(
+            patrols(&input),
+            [
+                Patrol {
+                    start: Point::new(1, 1),
+                    direction: Direction::Up,
+                    visited: 8,
+                    outcome: Outcome::Loops { cycle_length: 8 },
+                },
+                Patrol {
+                    start: Point::new(2, 2),
+                    direction: Direction::Down,
+                    visited: 3,
+                    outcome: Outcome::Leaves,
+                },
+            ]
+        );
+        assert_eq!(
+            patrols(&input)
+                .iter()
+                .map(Patrol::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "guard at 1,1 facing Up: visits 8 positions and loops every 8 steps",
+                "guard at 2,2 facing Down: visits 3 positions and leaves",
+            ]
+        );
+        assert_eq!(part1(&input), Answer::Number(10));
+    }
+}
@@ -0,0 +1,52 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 850;
+
+// Any combination of operators on numbers with this many digits in total
+// stays below 10^18, so it fits in a u64
+const MAX_DIGITS: usize = 18;
+
+/// `size` equations of two to twelve numbers. About half of the test values
+/// come from combining the numbers with random operators, the rest are off
+/// by a little and most likely impossible.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    (0..size)
+        .map(|_| {
+            let length = rng.between(2, 12) as usize;
+            let mut numbers = Vec::new();
+            let mut digits = 0;
+            while numbers.len() < length {
+                let number = match rng.below(3) {
+                    0 => rng.between(1, 9),
+                    1 => rng.between(10, 99),
+                    _ => rng.between(100, 999),
+                } as u64;
+                digits += number.to_string().len();
+                if digits > MAX_DIGITS {
+                    break;
+                }
+                numbers.push(number);
+            }
+            if numbers.len() < 2 {
+                numbers.push(rng.between(1, 9) as u64);
+            }
+
+            let mut total = numbers[0];
+            for &number in &numbers[1..] {
+                total = match rng.below(3) {
+                    0 => total + number,
+                    1 => total * number,
+                    _ => format!("{total}{number}").parse().unwrap(),
+                };
+            }
+            if rng.chance(0.5) {
+                total += rng.between(1, 9) as u64;
+            }
+
+            let numbers = numbers.iter().map(u64::to_string).collect::<Vec<_>>();
+            format!("{total}: {}\n", numbers.join(" "))
+        })
+        .collect()
+}
@@ -0,0 +1,179 @@
+use std::{error::Error, fmt};
+
+use common::{Answer, InputError, Location};
+use operator::{solve, Operator, PART1, PART2};
+
+pub mod generate;
+pub mod operator;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    MissingColon { location: Location },
+    ExpectedNumber { location: Location, found: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingColon { .. } => {
+                write!(f, "expected a test value followed by \": \"")
+            }
+            ParseError::ExpectedNumber { found, .. } => {
+                write!(f, "expected a number, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::MissingColon { location } | ParseError::ExpectedNumber { location, .. } => {
+                *location
+            }
+        }
+    }
+}
+
+// Test values can be larger than a u64
+pub type Input = Vec<(u128, Vec<u128>)>;
+
+fn parse_number(input: &str, number: &str) -> Result<u128, ParseError> {
+    number.parse().map_err(|_| ParseError::ExpectedNumber {
+        location: Location::of(input, number),
+        found: String::from(number),
+    })
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (total_input, sequence_input) =
+                line.split_once(": ")
+                    .ok_or_else(|| ParseError::MissingColon {
+                        location: Location::of(input, line),
+                    })?;
+            let total = parse_number(input, total_input)?;
+            let sequence = sequence_input
+                .split(' ')
+                .map(|n| parse_number(input, n))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok((total, sequence))
+        })
+        .collect()
+}
+
+// Add two numbers written out in decimal, for sums that don't fit in a u128
+fn add_decimal(a: &str, b: &str) -> String {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut digits = Vec::new();
+    let mut carry = 0;
+
+    for i in 0..a.len().max(b.len()) {
+        let digit = |number: &[u8]| {
+            number
+                .len()
+                .checked_sub(i + 1)
+                .map_or(0, |i| number[i] - b'0')
+        };
+        let sum = digit(a) + digit(b) + carry;
+        digits.push(b'0' + sum % 10);
+        carry = sum / 10;
+    }
+    if carry > 0 {
+        digits.push(b'0' + carry);
+    }
+
+    digits
+        .iter()
+        .rev()
+        .map(|&digit| char::from(digit))
+        .collect()
+}
+
+// The sum of the test values of the equations that can be made true, as
+// text when it doesn't fit in a u64
+fn total_calibration_result(input: &Input, operators: &[&dyn Operator]) -> Answer {
+    let mut total = Ok(0u128);
+    for &(value, ref sequence) in input {
+        if solve(value, sequence, operators).is_none() {
+            continue;
+        }
+
+        // Once the sum overflows it continues in decimal
+        total = match total {
+            Ok(total) => total
+                .checked_add(value)
+                .ok_or_else(|| add_decimal(&total.to_string(), &value.to_string())),
+            Err(total) => Err(add_decimal(&total, &value.to_string())),
+        };
+    }
+
+    match total {
+        Ok(total) => match u64::try_from(total) {
+            Ok(total) => total.into(),
+            Err(_) => total.to_string().into(),
+        },
+        Err(total) => total.into(),
+    }
+}
+
+pub fn part1(input: &Input) -> Answer {
+    total_calibration_result(input, PART1)
+}
+
+pub fn part2(input: &Input) -> Answer {
+    total_calibration_result(input, PART2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(3749));
+        assert_eq!(part2(&input), Answer::Number(11387));
+    }
+
+    #[test]
+    fn above_u64() {
+        let input = parse("36893488147419103232: 18446744073709551616 2\n").unwrap();
+
+        assert_eq!(part1(&input), Answer::from("36893488147419103232"));
+
+        let max = u128::MAX.to_string();
+        let input = parse(&format!("{max}: {max} 1\n{max}: {max}\n2: 1 1\n")).unwrap();
+
+        assert_eq!(
+            part1(&input),
+            Answer::from("680564733841876926926749214863536422912")
+        );
+    }
+
+    #[test]
+    fn zero_operands() {
+        let input = parse("3: 3 0\n0: 7 3 0\n50: 5 0\n1: 1 0 0\n").unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(4));
+        assert_eq!(part2(&input), Answer::Number(54));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse("10: 5 x").err(),
+            Some(ParseError::ExpectedNumber {
+                location: Location::new(1, 7),
+                found: String::from("x"),
+            })
+        );
+    }
+}
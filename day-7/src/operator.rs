@@ -0,0 +1,266 @@
+/// An operator that can be placed between two numbers of an equation.
+/// Equations are always evaluated left to right.
+pub trait Operator {
+    fn symbol(&self) -> &'static str;
+
+    /// `left` combined with `right`, `None` when the result doesn't fit or
+    /// isn't defined
+    fn apply(&self, left: u128, right: u128) -> Option<u128>;
+
+    /// The `left` for which `apply(left, right)` gives `result`, `None` when
+    /// there is no such number or, see `constant`, when every number does
+    fn invert(&self, result: u128, right: u128) -> Option<u128>;
+
+    /// What `apply(left, right)` gives for every `left`, like `* 0`, `None`
+    /// when the result depends on `left`
+    fn constant(&self, _right: u128) -> Option<u128> {
+        None
+    }
+}
+
+pub struct Add;
+
+impl Operator for Add {
+    fn symbol(&self) -> &'static str {
+        "+"
+    }
+
+    fn apply(&self, left: u128, right: u128) -> Option<u128> {
+        left.checked_add(right)
+    }
+
+    fn invert(&self, result: u128, right: u128) -> Option<u128> {
+        result.checked_sub(right)
+    }
+}
+
+pub struct Mul;
+
+impl Operator for Mul {
+    fn symbol(&self) -> &'static str {
+        "*"
+    }
+
+    fn apply(&self, left: u128, right: u128) -> Option<u128> {
+        left.checked_mul(right)
+    }
+
+    fn invert(&self, result: u128, right: u128) -> Option<u128> {
+        (right != 0 && result.is_multiple_of(right)).then(|| result / right)
+    }
+
+    fn constant(&self, right: u128) -> Option<u128> {
+        (right == 0).then_some(0)
+    }
+}
+
+/// The digits of `left` followed by those of `right`
+pub struct Concat;
+
+// The power of 10 to shift a number by to make room for the digits of `n`
+fn magnitude(n: u128) -> Option<u128> {
+    let mut magnitude = 10u128;
+    while n >= magnitude {
+        magnitude = magnitude.checked_mul(10)?;
+    }
+    Some(magnitude)
+}
+
+impl Operator for Concat {
+    fn symbol(&self) -> &'static str {
+        "||"
+    }
+
+    fn apply(&self, left: u128, right: u128) -> Option<u128> {
+        left.checked_mul(magnitude(right)?)?.checked_add(right)
+    }
+
+    fn invert(&self, result: u128, right: u128) -> Option<u128> {
+        let magnitude = magnitude(right)?;
+        (result % magnitude == right).then(|| result / magnitude)
+    }
+}
+
+/// Subtraction, without going below zero
+pub struct Sub;
+
+impl Operator for Sub {
+    fn symbol(&self) -> &'static str {
+        "-"
+    }
+
+    fn apply(&self, left: u128, right: u128) -> Option<u128> {
+        left.checked_sub(right)
+    }
+
+    fn invert(&self, result: u128, right: u128) -> Option<u128> {
+        result.checked_add(right)
+    }
+}
+
+/// Bitwise exclusive or
+pub struct Xor;
+
+impl Operator for Xor {
+    fn symbol(&self) -> &'static str {
+        "^"
+    }
+
+    fn apply(&self, left: u128, right: u128) -> Option<u128> {
+        Some(left ^ right)
+    }
+
+    fn invert(&self, result: u128, right: u128) -> Option<u128> {
+        Some(result ^ right)
+    }
+}
+
+/// The operators of part 1
+pub const PART1: &[&dyn Operator] = &[&Add, &Mul];
+
+/// The operators of part 2
+pub const PART2: &[&dyn Operator] = &[&Add, &Mul, &Concat];
+
+/// Evaluate `sequence` left to right with `operators` between the numbers,
+/// `None` when an operator can't be applied
+pub fn evaluate(sequence: &[u128], operators: &[&dyn Operator]) -> Option<u128> {
+    let (&first, rest) = sequence.split_first()?;
+    rest.iter()
+        .zip(operators)
+        .try_fold(first, |value, (&next, operator)| {
+            operator.apply(value, next)
+        })
+}
+
+// Whether any operators between `value` and the numbers of `sequence` can
+// be applied, pushing the first such operators found
+fn evaluates(
+    value: u128,
+    sequence: &[u128],
+    operators: &[&dyn Operator],
+    symbols: &mut Vec<&'static str>,
+) -> bool {
+    let Some((&next, rest)) = sequence.split_first() else {
+        return true;
+    };
+
+    for operator in operators {
+        if let Some(value) = operator.apply(value, next) {
+            symbols.push(operator.symbol());
+            if evaluates(value, rest, operators, symbols) {
+                return true;
+            }
+            symbols.pop();
+        }
+    }
+
+    false
+}
+
+// Works backwards from the test value by undoing the operator before the
+// last number, which rules out most operators straight away: `*` only
+// when the last number divides the test value, `||` only when the test
+// value ends in it and `+` only when it isn't larger. The operators are
+// pushed once the equation works out, so they end up in order. An operator
+// that gives the test value whatever the left side is, like `* 0`, only
+// needs the numbers before it to evaluate.
+fn search(
+    total: u128,
+    sequence: &[u128],
+    operators: &[&dyn Operator],
+    symbols: &mut Vec<&'static str>,
+) -> bool {
+    let Some((&last, rest)) = sequence.split_last() else {
+        return false;
+    };
+    if rest.is_empty() {
+        return total == last;
+    }
+
+    for operator in operators {
+        if operator.constant(last) == Some(total) {
+            let (&first, rest) = rest.split_first().unwrap();
+            if evaluates(first, rest, operators, symbols) {
+                symbols.push(operator.symbol());
+                return true;
+            }
+        } else if let Some(left) = operator.invert(total, last) {
+            if search(left, rest, operators, symbols) {
+                symbols.push(operator.symbol());
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// The operators between the numbers of `sequence` that make it evaluate to
+/// `total`, `None` when no combination does
+pub fn solve(
+    total: u128,
+    sequence: &[u128],
+    operators: &[&dyn Operator],
+) -> Option<Vec<&'static str>> {
+    let mut symbols = Vec::new();
+    search(total, sequence, operators, &mut symbols).then_some(symbols)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operators_invert() {
+        let operators: [&dyn Operator; 5] = [&Add, &Mul, &Concat, &Sub, &Xor];
+
+        for operator in operators {
+            for (left, right) in [(12, 345), (6, 3), (100, 10), (7, 1)] {
+                if let Some(result) = operator.apply(left, right) {
+                    assert_eq!(
+                        operator.invert(result, right),
+                        Some(left),
+                        "{left} {} {right}",
+                        operator.symbol()
+                    );
+                }
+            }
+        }
+        assert_eq!(Concat.apply(12, 345), Some(12345));
+        assert_eq!(Concat.invert(12345, 45), Some(123));
+        assert_eq!(Concat.invert(12345, 44), None);
+        assert_eq!(Concat.apply(2, u128::MAX), None);
+        assert_eq!(evaluate(&[6, 8, 6, 15], &[&Mul, &Concat, &Mul]), Some(7290));
+    }
+
+    #[test]
+    fn solve_with_any_operators() {
+        assert_eq!(solve(3267, &[81, 40, 27], PART1), Some(vec!["*", "+"]));
+        assert_eq!(solve(7290, &[6, 8, 6, 15], PART1), None);
+        assert_eq!(
+            solve(7290, &[6, 8, 6, 15], PART2),
+            Some(vec!["*", "||", "*"])
+        );
+
+        let operators: &[&dyn Operator] = &[&Sub, &Xor];
+        assert_eq!(solve(4, &[10, 3, 5], operators), Some(vec!["^", "-"]));
+        assert_eq!(solve(0, &[10, 3, 5], operators), None);
+        assert_eq!(solve(5, &[5], PART1), Some(vec![]));
+
+        assert_eq!(solve(0, &[5, 0], PART1), Some(vec!["*"]));
+        assert_eq!(solve(5, &[5, 0], PART1), Some(vec!["+"]));
+        assert_eq!(solve(0, &[3, 4, 0], PART1), Some(vec!["+", "*"]));
+        assert_eq!(solve(50, &[5, 0], PART1), None);
+        assert_eq!(solve(50, &[5, 0], PART2), Some(vec!["||"]));
+        assert_eq!(solve(100, &[1, 0, 0], PART2), Some(vec!["||", "||"]));
+        // The numbers before `* 0` still have to evaluate
+        assert_eq!(solve(0, &[u128::MAX, 1, 0], PART1), Some(vec!["*", "*"]));
+        assert_eq!(solve(0, &[u128::MAX, 2, 0], PART1), None);
+
+        let above_u64 = u64::MAX as u128 * 10 + 7;
+        assert_eq!(
+            solve(above_u64, &[u64::MAX as u128, 7], PART2),
+            Some(vec!["||"])
+        );
+    }
+}
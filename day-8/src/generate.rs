@@ -0,0 +1,24 @@
+use common::Rng;
+use grid::{Grid, Point};
+
+pub const DEFAULT_SIZE: usize = 50;
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A `size` by `size` map with three to five antennas for each of about
+/// `size` / 1.5 frequencies
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    let mut grid = Grid::new(size, size, '.');
+    let frequencies = (size * 2 / 3).clamp(1, FREQUENCIES.len());
+    for &frequency in &FREQUENCIES[..frequencies] {
+        for _ in 0..rng.between(3, 5) {
+            let point = Point::new(rng.index(size) as isize, rng.index(size) as isize);
+            grid[point] = frequency as char;
+        }
+    }
+
+    format!("{grid}\n")
+}
@@ -0,0 +1,121 @@
+use std::collections::{HashMap, HashSet};
+
+use common::Answer;
+pub use grid::ParseError;
+use grid::{Grid, Image, Point, Rgb};
+use itertools::Itertools;
+
+pub mod generate;
+
+type Antennas = HashMap<char, Vec<Point>>;
+
+pub struct Input {
+    grid: Grid<char>,
+    antennas: Antennas,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let grid = Grid::try_parse(input, "'.' or an antenna letter or digit", |c| {
+        (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+    })?;
+
+    let antennas =
+        grid.iter()
+            .filter(|(_, c)| **c != '.')
+            .fold(Antennas::new(), |mut acc, (position, c)| {
+                acc.entry(*c).or_default().push(position);
+                acc
+            });
+
+    Ok(Input { grid, antennas })
+}
+
+fn antinodes(input: &Input) -> HashSet<Point> {
+    input
+        .antennas
+        .values()
+        .flat_map(|antenna_positions| {
+            antenna_positions
+                .iter()
+                .tuple_combinations()
+                .flat_map(|(a, b)| {
+                    let diff = *a - *b;
+
+                    [*a + diff, *b - diff]
+                })
+        })
+        .filter(|position| input.grid.contains(*position))
+        .collect()
+}
+
+// Antinodes at any multiple of the distance between two antennas
+fn resonant_antinodes(input: &Input) -> HashSet<Point> {
+    let rows = input.grid.height() as isize;
+
+    input
+        .antennas
+        .values()
+        .flat_map(|antenna_positions| {
+            antenna_positions
+                .iter()
+                .tuple_combinations()
+                .flat_map(move |(a, b)| {
+                    let diff = *a - *b;
+
+                    (0..rows).flat_map(move |i| [*a + diff * i, *b - diff * i])
+                })
+        })
+        .filter(|position| input.grid.contains(*position))
+        .collect()
+}
+
+pub fn part1(input: &Input) -> Answer {
+    antinodes(input).len().into()
+}
+
+pub fn part2(input: &Input) -> Answer {
+    resonant_antinodes(input).len().into()
+}
+
+/// The antennas in a colour per frequency, the antinodes of part 1 in
+/// yellow and the extra antinodes of part 2 in grey
+pub fn image(input: &Input) -> Image {
+    let mut image = Image::new(&input.grid, |_, _| Rgb::BLACK);
+    image.highlight(resonant_antinodes(input), Rgb::GREY);
+    image.highlight(antinodes(input), Rgb::YELLOW);
+    for (frequency, positions) in &input.antennas {
+        image.highlight(positions.iter().copied(), Rgb::from_key(*frequency as u64));
+    }
+
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(14));
+        assert_eq!(part2(&input), Answer::Number(34));
+    }
+
+    #[test]
+    fn sample_input_2() {
+        let input = "..........
+..........
+..........
+....a.....
+..........
+.....a....
+..........
+..........
+..........
+..........";
+
+        assert_eq!(part1(&parse(input).unwrap()), Answer::Number(2));
+    }
+}
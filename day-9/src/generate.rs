@@ -0,0 +1,23 @@
+use common::Rng;
+
+pub const DEFAULT_SIZE: usize = 19999;
+
+/// A disk map of `size` digits, alternating between files of one to nine
+/// blocks and zero to nine blocks of free space
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let mut disk_map = (0..size)
+        .map(|i| {
+            let digit = if i % 2 == 0 {
+                rng.between(1, 9)
+            } else {
+                rng.between(0, 9)
+            };
+            char::from(b'0' + digit as u8)
+        })
+        .collect::<String>();
+    disk_map.push('\n');
+
+    disk_map
+}
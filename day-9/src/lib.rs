@@ -0,0 +1,150 @@
+use std::{cell::RefCell, error::Error, fmt, iter::repeat_n};
+
+use common::{Answer, InputError, Location};
+use itertools::Itertools;
+
+pub mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    ExpectedDigit { location: Location, found: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::ExpectedDigit { found, .. } => {
+                write!(f, "expected a digit, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::ExpectedDigit { location, .. } => *location,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct File {
+    id: usize,
+    length: usize,
+    free_space: usize,
+}
+
+pub type Input = Vec<File>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let digits = input
+        .char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(offset, c)| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| ParseError::ExpectedDigit {
+                    location: Location::of(input, &input[offset..]),
+                    found: c,
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(digits
+        .into_iter()
+        .chunks(2)
+        .into_iter()
+        .map(|mut chunk| {
+            let length = chunk.next().unwrap();
+            let free_space = chunk.next().unwrap_or(0);
+            (length, free_space)
+        })
+        .enumerate()
+        .map(|(id, (length, free_space))| File {
+            id,
+            length,
+            free_space,
+        })
+        .collect::<Vec<_>>())
+}
+
+pub fn part1(files: &Input) -> Answer {
+    let total_length = files.iter().map(|file| file.length).sum::<usize>();
+
+    let mut reverse_file_it = files
+        .iter()
+        .rev()
+        .flat_map(|file| repeat_n(file.id, file.length));
+
+    files
+        .iter()
+        .flat_map(|file| {
+            repeat_n(file.id, file.length).chain(
+                reverse_file_it
+                    .by_ref()
+                    .take(file.free_space)
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .take(total_length)
+        .enumerate()
+        .map(|(position, file_id)| position * file_id)
+        .sum::<usize>()
+        .into()
+}
+
+pub fn part2(files: &Input) -> Answer {
+    let placed_files = RefCell::new(vec![false; files.len()]);
+
+    files
+        .iter()
+        .flat_map(|file| {
+            let value = if placed_files.borrow()[file.id] {
+                0
+            } else {
+                placed_files.borrow_mut()[file.id] = true;
+                file.id
+            };
+            let iterator = repeat_n(value, file.length);
+
+            let (free_space, file_it) = files
+                .iter()
+                .rev()
+                .filter(|file| !placed_files.borrow()[file.id])
+                .fold(
+                    (file.free_space, Vec::new()),
+                    |(free_space, mut acc), file| {
+                        if free_space >= file.length {
+                            acc.push(repeat_n(file.id, file.length));
+                            placed_files.borrow_mut()[file.id] = true;
+                            (free_space - file.length, acc)
+                        } else {
+                            (free_space, acc)
+                        }
+                    },
+                );
+
+            iterator.chain(file_it.into_iter().flatten().chain(repeat_n(0, free_space)))
+        })
+        .enumerate()
+        .map(|(position, file_id)| position * file_id)
+        .sum::<usize>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_input_1() {
+        let input = include_str!("../input_sample");
+        let input = parse(input).unwrap();
+
+        assert_eq!(part1(&input), Answer::Number(1928));
+        assert_eq!(part2(&input), Answer::Number(2858));
+    }
+}
@@ -0,0 +1,375 @@
+use std::{fmt::Write as _, fs, io, path::Path};
+
+use crate::{Grid, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(60, 180, 75);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(255, 200, 0);
+
+    /// A colour `fraction` of the way from `self` to `other`
+    pub fn blend(self, other: Rgb, fraction: f64) -> Rgb {
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * fraction).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+
+    /// A bright colour that is always the same for the same `key`, to tell
+    /// apart many kinds of cells like antenna frequencies or garden plants
+    pub fn from_key(key: u64) -> Rgb {
+        // Spread neighbouring keys over the colour wheel by the golden ratio
+        let hue = (key as f64 * 0.618_033_988_75).fract() * 6.0;
+        let rising = (hue.fract() * 200.0) as u8 + 55;
+        let falling = 255 - rising + 55;
+        match hue as u8 {
+            0 => Rgb(255, rising, 55),
+            1 => Rgb(falling, 255, 55),
+            2 => Rgb(55, 255, rising),
+            3 => Rgb(55, falling, 255),
+            4 => Rgb(rising, 55, 255),
+            _ => Rgb(255, 55, falling),
+        }
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    /// The format that belongs to the extension of `path`
+    pub fn from_path(path: &Path) -> Option<ImageFormat> {
+        match path.extension()?.to_str()? {
+            "ppm" => Some(ImageFormat::Ppm),
+            "png" => Some(ImageFormat::Png),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// A picture of a grid with one coloured square per cell, plus lines drawn
+/// through the middle of cells on top of them
+#[derive(Debug, Clone)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    cells: Vec<Rgb>,
+    paths: Vec<(Vec<Point>, Rgb)>,
+}
+
+impl Image {
+    /// Colour every cell of `grid` with `palette`
+    pub fn new<T>(grid: &Grid<T>, mut palette: impl FnMut(Point, &T) -> Rgb) -> Image {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            cells: grid
+                .iter()
+                .map(|(point, cell)| palette(point, cell))
+                .collect(),
+            paths: Vec::new(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<Rgb> {
+        self.index(point).map(|i| self.cells[i])
+    }
+
+    /// Recolour a set of cells, like the positions that were visited.
+    /// Points outside of the image are ignored.
+    pub fn highlight(&mut self, points: impl IntoIterator<Item = Point>, colour: Rgb) {
+        for point in points {
+            if let Some(i) = self.index(point) {
+                self.cells[i] = colour;
+            }
+        }
+    }
+
+    /// Draw a line through the middle of `points`, in order
+    pub fn path(&mut self, points: Vec<Point>, colour: Rgb) {
+        self.paths.push((points, colour));
+    }
+
+    /// The image as a file in `format`, every cell is `scale` by `scale`
+    /// pixels (SVG units)
+    pub fn encode(&self, format: ImageFormat, scale: usize) -> Vec<u8> {
+        match format {
+            ImageFormat::Ppm => self.ppm(scale),
+            ImageFormat::Png => self.png(scale),
+            ImageFormat::Svg => self.svg(scale).into_bytes(),
+        }
+    }
+
+    /// Write the image in the format that matches the extension of `path`
+    pub fn save(&self, path: &Path, scale: usize) -> io::Result<()> {
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "expected a .ppm, .png or .svg file",
+            )
+        })?;
+
+        fs::write(path, self.encode(format, scale))
+    }
+
+    /// A binary PPM (P6) file
+    pub fn ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height, pixels) = self.rasterise(scale);
+
+        let mut file = format!("P6\n{width} {height}\n255\n").into_bytes();
+        file.extend(pixels.iter().flat_map(|Rgb(r, g, b)| [*r, *g, *b]));
+        file
+    }
+
+    /// A PNG file. The pixel data is stored without compression, which keeps
+    /// the encoder small at the cost of larger files.
+    pub fn png(&self, scale: usize) -> Vec<u8> {
+        let (width, height, pixels) = self.rasterise(scale);
+
+        let mut header = Vec::new();
+        header.extend((width as u32).to_be_bytes());
+        header.extend((height as u32).to_be_bytes());
+        // 8 bit RGB, default compression and filtering, not interlaced
+        header.extend([8, 2, 0, 0, 0]);
+
+        // Every scanline starts with filter type 0, no filtering
+        let mut scanlines = Vec::with_capacity(height * (1 + 3 * width));
+        for row in pixels.chunks(width.max(1)) {
+            scanlines.push(0);
+            scanlines.extend(row.iter().flat_map(|Rgb(r, g, b)| [*r, *g, *b]));
+        }
+
+        let mut file = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut file, b"IHDR", &header);
+        png_chunk(&mut file, b"IDAT", &zlib_stored(&scanlines));
+        png_chunk(&mut file, b"IEND", &[]);
+        file
+    }
+
+    /// An SVG file with a rectangle for every run of equally coloured cells
+    /// in a row and a polyline for every path
+    pub fn svg(&self, scale: usize) -> String {
+        let (width, height) = (self.width * scale, self.height * scale);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n"
+        );
+
+        for y in 0..self.height {
+            let row = &self.cells[y * self.width..(y + 1) * self.width];
+            let mut x = 0;
+            while x < row.len() {
+                let run = row[x..].iter().take_while(|cell| **cell == row[x]).count();
+                writeln!(
+                    svg,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{scale}\" fill=\"{}\"/>",
+                    x * scale,
+                    y * scale,
+                    run * scale,
+                    row[x].hex()
+                )
+                .unwrap();
+                x += run;
+            }
+        }
+
+        for (points, colour) in &self.paths {
+            let points = points
+                .iter()
+                .map(|point| {
+                    let (x, y) = self.centre(*point, scale);
+                    format!("{x},{y}")
+                })
+                .collect::<Vec<_>>();
+            writeln!(
+                svg,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" \
+                 stroke-linejoin=\"round\"/>",
+                points.join(" "),
+                colour.hex(),
+                line_width(scale)
+            )
+            .unwrap();
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        let in_bounds = (0..self.width as isize).contains(&point.x)
+            && (0..self.height as isize).contains(&point.y);
+        in_bounds.then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    fn centre(&self, point: Point, scale: usize) -> (isize, isize) {
+        let half = scale as isize / 2;
+        (
+            point.x * scale as isize + half,
+            point.y * scale as isize + half,
+        )
+    }
+
+    // The width, height and pixels row by row with the paths drawn in
+    fn rasterise(&self, scale: usize) -> (usize, usize, Vec<Rgb>) {
+        let (width, height) = (self.width * scale, self.height * scale);
+        let mut pixels = vec![Rgb::BLACK; width * height];
+        for (i, cell) in self.cells.iter().enumerate() {
+            let (x, y) = (i % self.width * scale, i / self.width * scale);
+            for row in y..y + scale {
+                pixels[row * width + x..row * width + x + scale].fill(*cell);
+            }
+        }
+
+        let thickness = line_width(scale) as isize;
+        let mut plot = |x: isize, y: isize, colour: Rgb| {
+            for py in y - thickness / 2..y - thickness / 2 + thickness {
+                for px in x - thickness / 2..x - thickness / 2 + thickness {
+                    if (0..width as isize).contains(&px) && (0..height as isize).contains(&py) {
+                        pixels[py as usize * width + px as usize] = colour;
+                    }
+                }
+            }
+        };
+        for (points, colour) in &self.paths {
+            if let [point] = points[..] {
+                let (x, y) = self.centre(point, scale);
+                plot(x, y, *colour);
+            }
+            for pair in points.windows(2) {
+                let (from, to) = (self.centre(pair[0], scale), self.centre(pair[1], scale));
+                let steps = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1)).max(1) as isize;
+                for step in 0..=steps {
+                    plot(
+                        from.0 + (to.0 - from.0) * step / steps,
+                        from.1 + (to.1 - from.1) * step / steps,
+                        *colour,
+                    );
+                }
+            }
+        }
+
+        (width, height, pixels)
+    }
+}
+
+fn line_width(scale: usize) -> usize {
+    (scale / 4).max(1)
+}
+
+fn png_chunk(file: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    file.extend((data.len() as u32).to_be_bytes());
+    file.extend(kind);
+    file.extend(data);
+    file.extend(crc32(kind.iter().chain(data)).to_be_bytes());
+}
+
+fn crc32<'a>(bytes: impl Iterator<Item = &'a u8>) -> u32 {
+    let crc = bytes.fold(!0u32, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| {
+            if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            }
+        })
+    });
+    !crc
+}
+
+// A zlib stream of deflate blocks that store `data` as is
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let length = block.len() as u16;
+        stream.push(last as u8);
+        stream.extend(length.to_le_bytes());
+        stream.extend((!length).to_le_bytes());
+        stream.extend(block);
+    }
+
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    stream.extend(((b << 16) | a).to_be_bytes());
+    stream
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> Image {
+        let grid = Grid::parse("#.\n.#", |c| c).unwrap();
+        Image::new(
+            &grid,
+            |_, c| if *c == '#' { Rgb::BLACK } else { Rgb::WHITE },
+        )
+    }
+
+    #[test]
+    fn ppm_pixels() {
+        let mut image = image();
+        image.highlight([Point::new(1, 0), Point::new(5, 5)], Rgb::RED);
+
+        let ppm = image.ppm(1);
+        assert!(ppm.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(ppm[11..], [0, 0, 0, 220, 50, 47, 255, 255, 255, 0, 0, 0]);
+        assert_eq!(image.ppm(3).len(), 11 + 6 * 6 * 3);
+    }
+
+    #[test]
+    fn png_checksums() {
+        // Known values for the check string of the CRC-32 and Adler-32 specs
+        assert_eq!(crc32(b"123456789".iter()), 0xcbf4_3926);
+        assert!(zlib_stored(b"Wikipedia").ends_with(&0x11e6_0398u32.to_be_bytes()));
+
+        let png = image().png(2);
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x04\0\0\0\x04"));
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+    }
+
+    #[test]
+    fn svg_runs_and_paths() {
+        let mut image = image();
+        image.highlight([Point::new(0, 1)], Rgb::BLACK);
+        image.path(vec![Point::new(0, 0), Point::new(1, 1)], Rgb::RED);
+
+        let svg = image.svg(4);
+        assert!(svg.contains("<rect x=\"0\" y=\"4\" width=\"8\" height=\"4\" fill=\"#000000\"/>"));
+        assert!(svg.contains("points=\"2,2 6,6\""));
+        assert!(svg.contains("stroke=\"#dc322f\""));
+    }
+}
@@ -0,0 +1,491 @@
+use std::{
+    error::Error,
+    fmt,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub},
+};
+
+use common::{InputError, Location};
+
+pub use image::{Image, ImageFormat, Rgb};
+
+mod image;
+
+/// A position on a grid, x grows to the right and y grows downwards
+///
+/// Coordinates are signed so stepping off the edge of a grid gives a point
+/// that is simply not contained in it, instead of wrapping around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ZERO: Point = Point::new(0, 0);
+
+    // Offsets to all 8 surrounding points, clockwise starting to the right
+    pub const OFFSETS_8: [Point; 8] = [
+        Point::new(1, 0),
+        Point::new(1, 1),
+        Point::new(0, 1),
+        Point::new(-1, 1),
+        Point::new(-1, 0),
+        Point::new(-1, -1),
+        Point::new(0, -1),
+        Point::new(1, -1),
+    ];
+
+    pub const fn new(x: isize, y: isize) -> Point {
+        Point { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The 4 orthogonally adjacent points
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    /// The 8 orthogonally and diagonally adjacent points
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Point::OFFSETS_8
+            .into_iter()
+            .map(move |offset| self + offset)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    // Clockwise starting upwards
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// The direction for an arrow character like `^`, `>`, `v` and `<`
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    UnexpectedCharacter {
+        location: Location,
+        found: char,
+        expected: &'static str,
+    },
+    RowLength {
+        location: Location,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedCharacter {
+                found, expected, ..
+            } => write!(f, "expected {expected}, found {found:?}"),
+            ParseError::RowLength {
+                expected, found, ..
+            } => write!(
+                f,
+                "expected a row of {expected} characters like the first one, found {found}"
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl InputError for ParseError {
+    fn location(&self) -> Location {
+        match self {
+            ParseError::UnexpectedCharacter { location, .. }
+            | ParseError::RowLength { location, .. } => *location,
+        }
+    }
+}
+
+/// A rectangular grid of cells stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parse a grid with one row per line, converting every character with
+    /// `cell`
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Grid<T>, ParseError> {
+        Grid::try_parse(input, "any character", |c| Some(cell(c)))
+    }
+
+    /// Parse a grid with one row per line, converting every character with
+    /// `cell`. Characters for which `cell` returns `None` are reported as
+    /// errors, with `expected` describing the valid characters.
+    pub fn try_parse(
+        input: &str,
+        expected: &'static str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            let mut row_width = 0;
+            for (column, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or(ParseError::UnexpectedCharacter {
+                    location: Location::new(height + 1, column + 1),
+                    found: c,
+                    expected,
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                return Err(ParseError::RowLength {
+                    location: Location::new(height + 1, row_width.min(width) + 1),
+                    expected: width,
+                    found: row_width,
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as isize).contains(&point.x) && (0..self.height as isize).contains(&point.y)
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// All points in the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |y| (0..width).map(move |x| Point::new(x as isize, y as isize)))
+    }
+
+    /// All cells together with their position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The orthogonal neighbours of `point` that lie within the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours4()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` that lie within the grid
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours8()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The first position, row by row, whose cell matches `predicate`
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// All positions whose cell matches `predicate`
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Render the grid with one line per row, without a trailing newline
+    pub fn render(&self, mut cell: impl FnMut(Point, &T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for (point, value) in self.iter() {
+            if point.x == 0 && point.y > 0 {
+                output.push('\n');
+            }
+            output.push(cell(point, value));
+        }
+        output
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("point {point} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("point {point} is outside of the grid"))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|_, c| *c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_render() {
+        let input = "#..\n.#.\n..#";
+        let grid = Grid::parse(input, |c| c).unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[Point::new(1, 1)], '#');
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 3)), None);
+        assert_eq!(grid.to_string(), input);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let wall = |c| (c == '#').then_some(true);
+
+        assert_eq!(
+            Grid::try_parse("##\n#.", "'#'", wall),
+            Err(ParseError::UnexpectedCharacter {
+                location: Location::new(2, 2),
+                found: '.',
+                expected: "'#'",
+            })
+        );
+        assert_eq!(
+            Grid::try_parse("##\n#", "'#'", wall),
+            Err(ParseError::RowLength {
+                location: Location::new(2, 2),
+                expected: 2,
+                found: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::new(3, 2, 0);
+
+        assert_eq!(grid.neighbours4(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn find_positions() {
+        let grid = Grid::parse("a.b\n.ba", |c| c).unwrap();
+
+        assert_eq!(grid.find(&'b'), Some(Point::new(2, 0)));
+        assert_eq!(grid.find(&'c'), None);
+        assert_eq!(
+            grid.positions(|c| *c == 'a').collect::<Vec<_>>(),
+            vec![Point::new(0, 0), Point::new(2, 1)]
+        );
+    }
+
+    #[test]
+    fn directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(Direction::from_arrow(direction.arrow()), Some(direction));
+            assert_eq!(
+                Point::ZERO.step(direction).step(direction.reverse()),
+                Point::ZERO
+            );
+        }
+    }
+}